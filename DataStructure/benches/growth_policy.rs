@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use data_structure::linear::{Deque, GrowthPolicy, Stack};
+
+const SIZES: [usize; 3] = [1_000, 4_000, 16_000];
+
+fn policies() -> [(&'static str, GrowthPolicy); 4] {
+    [
+        ("Linear(10)", GrowthPolicy::Linear(10)),
+        ("Geometric(1.5)", GrowthPolicy::Geometric(1.5)),
+        ("Geometric(2.0)", GrowthPolicy::Geometric(2.0)),
+        ("Custom(doubling)", GrowthPolicy::Custom(Arc::new(|capacity| capacity * 2))),
+    ]
+}
+
+/// Push `n` items into an empty stack. Linear growth copies the whole buffer every few pushes, so
+/// the time per element grows with `n`, while geometric growth keeps it amortized constant.
+fn stack_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("growth_policy_stack_push");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        for (name, policy) in policies() {
+            group.bench_with_input(BenchmarkId::new(name, n), &n, |b, &n| {
+                b.iter(|| {
+                    let mut stack = Stack::with_capacity_and_policy(10, policy.clone());
+                    for i in 0..n {
+                        stack.push(i);
+                    }
+                    black_box(stack)
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Push `n` items on alternating ends of an empty deque, so every growth has to unroll a wrapped
+/// buffer.
+fn deque_push(c: &mut Criterion) {
+    let mut group = c.benchmark_group("growth_policy_deque_push");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        for (name, policy) in policies() {
+            group.bench_with_input(BenchmarkId::new(name, n), &n, |b, &n| {
+                b.iter(|| {
+                    let mut deque = Deque::with_capacity_and_policy(10, policy.clone());
+                    for i in 0..n {
+                        if i % 2 == 0 {
+                            deque.push_back(i);
+                        } else {
+                            deque.push_front(i);
+                        }
+                    }
+                    black_box(deque)
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = stack_push, deque_push
+}
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use data_structure::linear::Queue;
+
+const SIZES: [usize; 3] = [1_000, 4_000, 16_000];
+
+/// Drain a queue of `n` items. With a circular buffer the time per element stays constant as `n`
+/// grows, while shifting the remaining elements on each pop (reproduced here with
+/// `Vec::remove(0)`) makes the time per element grow linearly.
+fn drain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("queue_drain");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("Queue", n), &n, |b, &n| {
+            b.iter_batched(
+                || {
+                    let mut queue = Queue::new();
+                    for i in 0..n {
+                        queue.push(i);
+                    }
+                    queue
+                },
+                |mut queue| {
+                    while let Some(item) = queue.pop() {
+                        black_box(item);
+                    }
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("Vec::remove(0)", n), &n, |b, &n| {
+            b.iter_batched(
+                || (0..n).collect::<Vec<_>>(),
+                |mut vec| {
+                    while !vec.is_empty() {
+                        black_box(vec.remove(0));
+                    }
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// Keep a short queue alive while pushing and popping many items, so head and tail wrap around
+/// the buffer over and over.
+fn steady_state(c: &mut Criterion) {
+    let mut group = c.benchmark_group("queue_steady_state");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("Queue", n), &n, |b, &n| {
+            b.iter(|| {
+                let mut queue = Queue::new();
+                for i in 0..8 {
+                    queue.push(i);
+                }
+                for i in 0..n {
+                    queue.push(i);
+                    black_box(queue.pop());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, drain, steady_state);
+criterion_main!(benches);
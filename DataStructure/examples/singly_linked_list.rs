@@ -0,0 +1,56 @@
+use data_structure::linear::SinglyLinkedList;
+
+fn main() {
+    println!("Trying pushes and pops");
+    let mut ll: SinglyLinkedList<i32> = SinglyLinkedList::<i32>::new();
+    ll.print();
+    ll.push_back(1);
+    ll.print();
+    ll.push_back(2);
+    ll.print();
+    ll.push_back(3);
+    ll.print();
+    ll.pop_back();
+    ll.print();
+    ll.push_front(5);
+    ll.print();
+    ll.push_front(6);
+    ll.print();
+    ll.push_back(7);
+    ll.print();
+    ll.pop_back();
+    ll.print();
+    ll.pop_front();
+    ll.print();
+    ll.push_back(7);
+    ll.push_front(8);
+    ll.push_front(13);
+    ll.print();
+    println!();
+    println!("Iterate with .iter");
+    let values: Vec<String> = ll.iter().map(|val| val.to_string()).collect();
+    println!("{}", values.join(" -> "));
+    println!("Iterate with .iter_mut");
+    for (i, val) in ll.iter_mut().enumerate() {
+        if i % 2 == 0 {
+            *val += 1;
+        }
+    }
+    println!("{}", ll);
+    println!(".print list");
+    ll.print();
+    println!();
+    println!("Use .find (Index position)");
+    print!("{}", ll.find(&8).unwrap());
+    if let Some(val) = ll.find(&11) { print!(" {}", val) } else { print!(" None") };
+    println!(" {}", ll.find(&7).unwrap());
+
+    let mut removed = ll.remove(4).unwrap();
+    println!("Removed {}", removed);
+    ll.print();
+    removed = ll.remove(ll.len() - 1).unwrap();
+    println!("Removed {}", removed);
+    ll.print();
+    ll.push_back(70);
+    ll.print();
+}
@@ -0,0 +1,57 @@
+//! Error type shared by every collection of the crate.
+//!
+//! Removing or peeking at an end of a collection only fails when it is empty: like in the
+//! standard collections, these operations (`pop`, `top`, `head`, ...) return an `Option`. Each
+//! has a counterpart reporting the empty collection as [`Error::Empty`], for callers that
+//! propagate failures with `?`: `try_pop*` for removals and `get_*` for references. The older
+//! [`pop_head`](crate::linear::DoubleLinkedList::pop_head) and
+//! [`pop_tail`](crate::linear::DoubleLinkedList::pop_tail) of the doubly linked list are aliases
+//! of its `try_pop_front` and `try_pop_back`.
+
+use std::alloc::{handle_alloc_error, Layout};
+use std::fmt::{Display, Formatter};
+
+/// Failure of a collection operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The index is not lower than the length of the collection (or not lower or equal, when
+    /// inserting).
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The operation needs at least one element but the collection is empty.
+    Empty,
+    /// The requested capacity overflows the maximum size an allocation can have.
+    CapacityExceeded,
+    /// The allocator failed to provide a buffer with the given layout.
+    AllocError { layout: Layout },
+}
+
+/// Result type returned by the fallible operations of the collections.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Aborts through [`handle_alloc_error`] for allocation failures, panics for other errors.
+    /// Used by the infallible operations that cannot report an error to their caller.
+    pub(crate) fn raise(self) -> ! {
+        match self {
+            Error::AllocError { layout } => handle_alloc_error(layout),
+            error => panic!("{}", error),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds (length is {})", index, len)
+            }
+            Error::Empty => write!(f, "collection is empty"),
+            Error::CapacityExceeded => write!(f, "capacity exceeded"),
+            Error::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
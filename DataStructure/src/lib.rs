@@ -0,0 +1,24 @@
+//! Data Structures implemented in Rust.
+//!
+//! Collections are grouped by shape: sequential ones live in [`linear`], the others in
+//! [`non_linear`]. The [`prelude`] re-exports every collection at once. Fallible operations
+//! report an [`Error`].
+//!
+//! ```
+//! use data_structure::prelude::*;
+//!
+//! let mut stack = Stack::new();
+//! stack.push(1);
+//! assert_eq!(stack.pop(), Some(1));
+//!
+//! let mut tree = BSTree::new();
+//! tree.insert(5);
+//! assert_eq!(tree.find(&5), Some(&5));
+//! ```
+
+pub mod error;
+pub mod linear;
+pub mod non_linear;
+pub mod prelude;
+
+pub use error::{Error, Result};
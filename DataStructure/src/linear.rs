@@ -1,6 +1,40 @@
-pub mod buffer_guard;
+//! Sequential collections.
+//!
+//! The buffer-backed ones report the number of slots of their buffer with `buffer_size`.
+//! Zero-sized elements take no memory: their buffers never allocate and always report
+//! `usize::MAX` slots. This is why the bounded collections ([`BoundedStack`], [`BoundedQueue`]
+//! and [`BoundedDeque`]) store their capacity themselves, instead of reading it from the buffer.
+
+mod raw_buffer;
+mod range;
+mod ring_buffer;
+mod ring_iter;
+pub mod growth_policy;
+#[cfg(test)]
+mod drop_tests;
+#[cfg(test)]
+mod test_utils;
 pub mod singly_linked_list;
 pub mod double_linked_list;
 pub mod stack;
 pub mod queue;
 pub mod deque;
+pub mod bounded_stack;
+pub mod bounded_queue;
+pub mod bounded_deque;
+pub mod array_stack;
+pub mod array_deque;
+pub mod small_stack;
+
+pub use singly_linked_list::SinglyLinkedList;
+pub use double_linked_list::DoubleLinkedList;
+pub use stack::Stack;
+pub use queue::Queue;
+pub use deque::Deque;
+pub use bounded_stack::BoundedStack;
+pub use bounded_queue::BoundedQueue;
+pub use bounded_deque::BoundedDeque;
+pub use array_stack::ArrayStack;
+pub use array_deque::ArrayDeque;
+pub use small_stack::SmallStack;
+pub use growth_policy::GrowthPolicy;
@@ -0,0 +1,225 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+use std::ptr;
+
+#[cfg(test)]
+mod tests;
+
+/// [`Deque`](super::Deque) storing up to `N` elements inline, without any heap allocation.
+///
+/// Elements live in a circular array like in [`Deque`](super::Deque). Pushing on a full deque
+/// hands the item back instead of growing.
+///
+/// ```
+/// use data_structure::linear::ArrayDeque;
+///
+/// let mut deque = ArrayDeque::<i32, 2>::new();
+/// assert_eq!(deque.push_back(1), Ok(()));
+/// assert_eq!(deque.push_front(0), Ok(()));
+/// assert_eq!(deque.push_back(2), Err(2));
+/// assert_eq!(deque.pop_back(), Some(1));
+/// ```
+pub struct ArrayDeque<T, const N: usize> {
+    length: usize,
+    buffer: [MaybeUninit<T>; N],
+    // Indexes for circular array: first element and first free slot after the last element
+    head_index: usize,
+    tail_index: usize,
+}
+
+impl<T, const N: usize> ArrayDeque<T, N> {
+    pub const fn new() -> ArrayDeque<T, N> {
+        ArrayDeque {
+            length: 0,
+            buffer: [const { MaybeUninit::uninit() }; N],
+            head_index: 0,
+            tail_index: 0,
+        }
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+    pub fn buffer_size(&self) -> usize {
+        N
+    }
+    pub fn buffer_is_full(&self) -> bool {
+        self.length == N
+    }
+
+    /// Push `item` before the head, or give it back if the deque is full
+    pub fn push_front(&mut self, item: T) -> Result<(), T> {
+        if self.buffer_is_full() {
+            return Err(item);
+        }
+        self.head_index = Self::wrap_sub(self.head_index, 1);
+        self.buffer[self.head_index].write(item);
+        self.length += 1;
+        Ok(())
+    }
+    /// Push `item` after the tail, or give it back if the deque is full
+    pub fn push_back(&mut self, item: T) -> Result<(), T> {
+        if self.buffer_is_full() {
+            return Err(item);
+        }
+        self.buffer[self.tail_index].write(item);
+        self.tail_index = Self::wrap_add(self.tail_index, 1);
+        self.length += 1;
+        Ok(())
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.length > 0 {
+            let to_return = unsafe { self.buffer[self.head_index].assume_init_read() };
+            self.head_index = Self::wrap_add(self.head_index, 1);
+            self.length -= 1;
+            return Some(to_return);
+        }
+        None
+    }
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.length > 0 {
+            self.tail_index = Self::wrap_sub(self.tail_index, 1);
+            let to_return = unsafe { self.buffer[self.tail_index].assume_init_read() };
+            self.length -= 1;
+            return Some(to_return);
+        }
+        None
+    }
+
+    pub fn head(&mut self) -> Option<&T> {
+        if self.length > 0 {
+            return Some(self.slot_ref(0));
+        }
+        None
+    }
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        if self.length > 0 {
+            return Some(self.slot_mut(0));
+        }
+        None
+    }
+    pub fn tail(&mut self) -> Option<&T> {
+        if self.length > 0 {
+            return Some(self.slot_ref(self.length - 1));
+        }
+        None
+    }
+    pub fn tail_mut(&mut self) -> Option<&mut T> {
+        if self.length > 0 {
+            return Some(self.slot_mut(self.length - 1));
+        }
+        None
+    }
+
+    /// Buffer index `offset` slots after `index`, wrapping around the end of the array
+    fn wrap_add(index: usize, offset: usize) -> usize {
+        let to_end = N - index;
+        if offset < to_end { index + offset } else { offset - to_end }
+    }
+
+    /// Buffer index `offset` slots before `index`, wrapping around the start of the array
+    fn wrap_sub(index: usize, offset: usize) -> usize {
+        if offset <= index { index - offset } else { N - (offset - index) }
+    }
+
+    /// The `index`-th element counting from the head, that must be lower than the length
+    fn slot_ref(&self, index: usize) -> &T {
+        debug_assert!(index < self.length);
+        unsafe { self.buffer[Self::wrap_add(self.head_index, index)].assume_init_ref() }
+    }
+    fn slot_mut(&mut self, index: usize) -> &mut T {
+        debug_assert!(index < self.length);
+        unsafe { self.buffer[Self::wrap_add(self.head_index, index)].assume_init_mut() }
+    }
+}
+
+impl<T: Display, const N: usize> Display for ArrayDeque<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Head -> [")?;
+        for i in 0..self.length {
+            write!(f, "{}", self.slot_ref(i))?;
+            if i != self.length - 1 {
+                write!(f, ", ")?;
+            }
+        }
+        write!(f, "] <- Tail")
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ArrayDeque<T, N> {
+    /// Elements from the head to the tail
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries((0..self.length).map(|i| self.slot_ref(i))).finish()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayDeque<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = ArrayDeque::new();
+        // Elements are counted one at a time: if a clone panics the copied ones are dropped
+        for i in 0..self.length {
+            // Same capacity as the original: never full here
+            let _ = clone.push_back(self.slot_ref(i).clone());
+        }
+        clone
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayDeque<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length
+            && (0..self.length).all(|i| self.slot_ref(i) == other.slot_ref(i))
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayDeque<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayDeque<T, N> {
+    /// Lexicographic comparison of the elements from the head to the tail
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let others = (0..other.length).map(|i| other.slot_ref(i));
+        (0..self.length).map(|i| self.slot_ref(i)).partial_cmp(others)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayDeque<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let others = (0..other.length).map(|i| other.slot_ref(i));
+        (0..self.length).map(|i| self.slot_ref(i)).cmp(others)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayDeque<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+        for i in 0..self.length {
+            self.slot_ref(i).hash(state);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayDeque<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayDeque<T, N> {
+    fn drop(&mut self) {
+        // Only the slots between head and tail (wrapping around the end) hold elements to drop
+        let front_len = self.length.min(N - self.head_index);
+        let base = self.buffer.as_mut_ptr() as *mut T;
+        unsafe {
+            let front = ptr::slice_from_raw_parts_mut(base.add(self.head_index), front_len);
+            let back = ptr::slice_from_raw_parts_mut(base, self.length - front_len);
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use super::ArrayDeque;
+use crate::linear::test_utils::assert_clone_eq_hash;
+use std::collections::VecDeque;
+
+const CAPACITY: usize = 6;
+
+fn assert_same(deque: &mut ArrayDeque<i32, CAPACITY>, expected: &VecDeque<i32>) {
+    assert_eq!(deque.length(), expected.len());
+    assert_eq!(deque.buffer_is_full(), expected.len() == CAPACITY);
+    assert_eq!(deque.head().copied(), expected.front().copied());
+    assert_eq!(deque.tail().copied(), expected.back().copied());
+    let values: Vec<String> = expected.iter().map(|v| v.to_string()).collect();
+    assert_eq!(format!("{}", deque), format!("Head -> [{}] <- Tail", values.join(", ")));
+}
+
+#[test]
+fn test_new() {
+    let deque = ArrayDeque::<i32, 3>::new();
+    assert!(deque.is_empty());
+    assert_eq!(deque.buffer_size(), 3);
+    assert_eq!(format!("{}", deque), "Head -> [] <- Tail");
+}
+
+#[test]
+fn test_against_vec_deque() {
+    // Deterministic mix of operations wrapping around both ends and hitting the capacity
+    let mut deque = ArrayDeque::<i32, CAPACITY>::new();
+    let mut expected = VecDeque::new();
+    for value in 0..500 {
+        match (value * 7 + value / 11) % 5 {
+            0 | 1 => {
+                let result = deque.push_back(value);
+                if expected.len() < CAPACITY {
+                    expected.push_back(value);
+                    assert_eq!(result, Ok(()));
+                } else {
+                    assert_eq!(result, Err(value));
+                }
+            }
+            2 => {
+                let result = deque.push_front(value);
+                if expected.len() < CAPACITY {
+                    expected.push_front(value);
+                    assert_eq!(result, Ok(()));
+                } else {
+                    assert_eq!(result, Err(value));
+                }
+            }
+            3 => assert_eq!(deque.pop_front(), expected.pop_front()),
+            _ => assert_eq!(deque.pop_back(), expected.pop_back()),
+        }
+        assert_same(&mut deque, &expected);
+    }
+}
+
+#[test]
+fn test_head_tail_mut() {
+    let mut deque = ArrayDeque::<i32, 3>::new();
+    assert_eq!(deque.head_mut(), None);
+    assert_eq!(deque.tail_mut(), None);
+    deque.push_front(1).unwrap();
+    deque.push_front(0).unwrap();
+    *deque.head_mut().unwrap() = 10;
+    *deque.tail_mut().unwrap() = 20;
+    assert_eq!(format!("{}", deque), "Head -> [10, 20] <- Tail");
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut deque = ArrayDeque::<i32, 0>::new();
+    assert!(deque.buffer_is_full());
+    assert_eq!(deque.push_back(1), Err(1));
+    assert_eq!(deque.push_front(1), Err(1));
+    assert_eq!(deque.pop_front(), None);
+    assert_eq!(deque.pop_back(), None);
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut deque = ArrayDeque::<(), 2>::new();
+    assert_eq!(deque.push_front(()), Ok(()));
+    assert_eq!(deque.push_back(()), Ok(()));
+    assert_eq!(deque.push_back(()), Err(()));
+    assert_eq!(deque.pop_back(), Some(()));
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let mut deque = ArrayDeque::<i32, 4>::new();
+    // Wrapped around the start of the array
+    deque.push_front(1).unwrap();
+    deque.push_front(0).unwrap();
+    deque.push_back(2).unwrap();
+    let mut other = ArrayDeque::<i32, 4>::new();
+    for item in 0..3 {
+        other.push_back(item).unwrap();
+    }
+    assert_clone_eq_hash(&deque, &other, |clone| {
+        clone.pop_front();
+    });
+    assert_eq!(format!("{}", deque.clone()), "Head -> [0, 1, 2] <- Tail");
+}
+
+#[test]
+fn test_debug_and_ord() {
+    let mut deque = ArrayDeque::<i32, 4>::new();
+    // Wrapped around the start of the array
+    deque.push_front(1).unwrap();
+    deque.push_front(0).unwrap();
+    deque.push_back(2).unwrap();
+    assert_eq!(format!("{:?}", deque), "[0, 1, 2]");
+    let mut other = ArrayDeque::<i32, 4>::new();
+    for item in 0..3 {
+        other.push_back(item).unwrap();
+    }
+    assert_eq!(other.cmp(&deque), std::cmp::Ordering::Equal);
+    other.pop_back();
+    assert!(other < deque);
+    other.push_front(1).unwrap();
+    assert!(other > deque);
+}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+use super::stack::Stack;
+
+#[cfg(test)]
+mod tests;
+
+/// [`Stack`](super::Stack) storing up to `N` elements inline, without any heap allocation.
+///
+/// Pushing on a full stack hands the item back instead of growing.
+///
+/// ```
+/// use data_structure::linear::ArrayStack;
+///
+/// let mut stack = ArrayStack::<i32, 2>::new();
+/// assert_eq!(stack.push(1), Ok(()));
+/// assert_eq!(stack.push(2), Ok(()));
+/// assert_eq!(stack.push(3), Err(3));
+/// assert_eq!(stack.pop(), Some(2));
+/// ```
+pub struct ArrayStack<T, const N: usize> {
+    length: usize,
+    // Slots below `length` are initialized
+    buffer: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> ArrayStack<T, N> {
+    pub const fn new() -> ArrayStack<T, N> {
+        ArrayStack { length: 0, buffer: [const { MaybeUninit::uninit() }; N] }
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+    pub fn buffer_size(&self) -> usize {
+        N
+    }
+    pub fn buffer_is_full(&self) -> bool {
+        self.length == N
+    }
+
+    /// Push `item` on top of the stack, or give it back if the stack is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.buffer_is_full() {
+            return Err(item);
+        }
+        self.buffer[self.length].write(item);
+        self.length += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length > 0 {
+            self.length -= 1;
+            return Some(unsafe { self.buffer[self.length].assume_init_read() });
+        }
+        None
+    }
+    pub fn top(&mut self) -> Option<&T> {
+        self.as_slice().last()
+    }
+    pub fn top_mut(&mut self) -> Option<&mut T> {
+        if self.length > 0 {
+            return Some(unsafe { self.buffer[self.length - 1].assume_init_mut() });
+        }
+        None
+    }
+
+    /// Elements from the bottom to the top of the stack
+    pub(super) fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buffer.as_ptr() as *const T, self.length) }
+    }
+    pub(super) fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.buffer.as_mut_ptr() as *mut T, self.length) }
+    }
+
+    /// Bottom slot and length, for the iterators editing the stack in place
+    pub(super) fn raw_parts_mut(&mut self) -> (*mut T, &mut usize) {
+        (self.buffer.as_mut_ptr() as *mut T, &mut self.length)
+    }
+
+    /// Move every element on top of `stack`, keeping their order
+    pub(super) fn move_into(&mut self, stack: &mut Stack<T>) {
+        // Forget the elements first: a panicking push leaks them instead of dropping them twice
+        let length = mem::replace(&mut self.length, 0);
+        for slot in &self.buffer[..length] {
+            stack.push(unsafe { slot.assume_init_read() });
+        }
+    }
+
+    /// Move every element of `stack` on top of this stack, keeping their order
+    pub(super) fn move_from(&mut self, stack: &mut Stack<T>) {
+        let count = stack.length();
+        assert!(count <= N - self.length, "moving {} elements past the array capacity", count);
+        // Popped from the top: slots are filled from the last one down
+        for slot in self.buffer[self.length..self.length + count].iter_mut().rev() {
+            slot.write(stack.pop().unwrap());
+        }
+        self.length += count;
+    }
+}
+
+impl<T: Display, const N: usize> Display for ArrayStack<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Top -> [")?;
+        for (i, item) in self.as_slice().iter().rev().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for ArrayStack<T, N> {
+    /// Elements from the top to the bottom
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.as_slice().iter().rev()).finish()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayStack<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = ArrayStack::new();
+        // Elements are counted one at a time: if a clone panics the copied ones are dropped
+        for item in self.as_slice() {
+            // Same capacity as the original: never full here
+            let _ = clone.push(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayStack<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayStack<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayStack<T, N> {
+    /// Lexicographic comparison of the elements from the top to the bottom
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().iter().rev().partial_cmp(other.as_slice().iter().rev())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayStack<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().iter().rev().cmp(other.as_slice().iter().rev())
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayStack<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> Default for ArrayStack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayStack<T, N> {
+    fn drop(&mut self) {
+        // Only the initialized slots hold elements to drop
+        let items = ptr::slice_from_raw_parts_mut(self.buffer.as_mut_ptr() as *mut T, self.length);
+        unsafe { ptr::drop_in_place(items) };
+    }
+}
@@ -0,0 +1,93 @@
+use super::ArrayStack;
+use crate::linear::test_utils::assert_clone_eq_hash;
+
+const STACK_ARRAY: [i32; 5] = [0, -1, 2, -3, 4];
+const STACK_DISPLAY: &str = "Top -> [4, -3, 2, -1, 0]";
+
+fn create_full_stack() -> ArrayStack<i32, 5> {
+    let mut stack = ArrayStack::new();
+    for &item in STACK_ARRAY.iter() {
+        assert_eq!(stack.push(item), Ok(()));
+    }
+    stack
+}
+
+#[test]
+fn test_new() {
+    let stack = ArrayStack::<i32, 3>::new();
+    assert!(stack.is_empty());
+    assert!(!stack.buffer_is_full());
+    assert_eq!(stack.buffer_size(), 3);
+    assert_eq!(stack.length(), 0);
+    // Usable in constant contexts
+    const EMPTY: ArrayStack<i32, 4> = ArrayStack::new();
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn test_push_pop() {
+    let mut stack = create_full_stack();
+    assert!(stack.buffer_is_full());
+    assert_eq!(stack.push(5), Err(5));
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    for &item in STACK_ARRAY.iter().rev() {
+        assert_eq!(stack.pop(), Some(item));
+    }
+    assert_eq!(stack.pop(), None);
+    assert_eq!(format!("{}", stack), "Top -> []");
+}
+
+#[test]
+fn test_top() {
+    let mut stack = create_full_stack();
+    assert_eq!(stack.top(), Some(&4));
+    *stack.top_mut().unwrap() = 10;
+    assert_eq!(stack.pop(), Some(10));
+    assert_eq!(stack.top(), Some(&-3));
+    let mut empty = ArrayStack::<i32, 1>::new();
+    assert_eq!(empty.top(), None);
+    assert_eq!(empty.top_mut(), None);
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut stack = ArrayStack::<String, 0>::new();
+    assert!(stack.buffer_is_full());
+    assert_eq!(stack.push("item".to_string()), Err("item".to_string()));
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut stack = ArrayStack::<(), 2>::new();
+    assert_eq!(stack.push(()), Ok(()));
+    assert_eq!(stack.push(()), Ok(()));
+    assert_eq!(stack.push(()), Err(()));
+    assert_eq!(stack.pop(), Some(()));
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let stack = create_full_stack();
+    let mut other = ArrayStack::<i32, 5>::new();
+    for &item in STACK_ARRAY.iter() {
+        other.push(item).unwrap();
+    }
+    assert_clone_eq_hash(&stack, &other, |clone| {
+        clone.pop();
+    });
+    assert_eq!(format!("{}", stack.clone()), STACK_DISPLAY);
+    assert!(ArrayStack::<i32, 5>::default() == ArrayStack::new());
+}
+
+#[test]
+fn test_debug_and_ord() {
+    let stack = create_full_stack();
+    assert_eq!(format!("{:?}", stack), "[4, -3, 2, -1, 0]");
+    let mut other = create_full_stack();
+    assert_eq!(other.cmp(&stack), std::cmp::Ordering::Equal);
+    other.pop();
+    assert!(other < stack);
+    assert_eq!(other.push(5), Ok(()));
+    assert!(other > stack);
+}
@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::deque::Deque;
+
+#[cfg(test)]
+mod tests;
+
+/// [`Deque`] holding at most a fixed number of elements.
+///
+/// The buffer is allocated once at construction and never reallocated: pushing on a full deque
+/// either hands the item back ([`push_back`](Self::push_back), [`push_front`](Self::push_front))
+/// or evicts the element at the opposite end to make room, turning the deque into a ring log
+/// ([`push_back_overwrite`](Self::push_back_overwrite),
+/// [`push_front_overwrite`](Self::push_front_overwrite)).
+///
+/// ```
+/// use data_structure::linear::BoundedDeque;
+///
+/// let mut log = BoundedDeque::new(3);
+/// for line in ["a", "b", "c"] {
+///     assert_eq!(log.push_back_overwrite(line), None);
+/// }
+/// // Full: the oldest line makes room for the new one
+/// assert_eq!(log.push_back_overwrite("d"), Some("a"));
+/// assert_eq!(log.push_back("e"), Err("e"));
+/// assert_eq!(log.to_string(), "Head -> [b, c, d] <- Tail");
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedDeque<T> {
+    deque: Deque<T>,
+    // Maximum length, see the linear module docs
+    capacity: usize,
+}
+
+impl<T> BoundedDeque<T> {
+    pub fn new(capacity: usize) -> BoundedDeque<T> {
+        BoundedDeque { deque: Deque::with_capacity(capacity), capacity }
+    }
+
+    pub fn length(&self) -> usize {
+        self.deque.length()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn is_full(&self) -> bool {
+        self.deque.length() == self.capacity
+    }
+
+    /// Push `item` before the head, or give it back if the deque is full
+    pub fn push_front(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.deque.push_front(item);
+        Ok(())
+    }
+    /// Push `item` after the tail, or give it back if the deque is full
+    pub fn push_back(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.deque.push_back(item);
+        Ok(())
+    }
+
+    /// Push `item` before the head. If the deque is full the tail is evicted to make room and
+    /// returned; with a zero capacity `item` itself is returned.
+    pub fn push_front_overwrite(&mut self, item: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(item);
+        }
+        let evicted = if self.is_full() { self.deque.pop_back() } else { None };
+        self.deque.push_front(item);
+        evicted
+    }
+    /// Push `item` after the tail. If the deque is full the head is evicted to make room and
+    /// returned; with a zero capacity `item` itself is returned.
+    pub fn push_back_overwrite(&mut self, item: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(item);
+        }
+        let evicted = if self.is_full() { self.deque.pop_front() } else { None };
+        self.deque.push_back(item);
+        evicted
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+    pub fn head(&mut self) -> Option<&T> {
+        self.deque.head()
+    }
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        self.deque.head_mut()
+    }
+    pub fn tail(&mut self) -> Option<&T> {
+        self.deque.tail()
+    }
+    pub fn tail_mut(&mut self) -> Option<&mut T> {
+        self.deque.tail_mut()
+    }
+}
+
+impl<T: Display> Display for BoundedDeque<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.deque.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for BoundedDeque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<T: Eq> Eq for BoundedDeque<T> {}
+
+impl<T: PartialOrd> PartialOrd for BoundedDeque<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.deque.partial_cmp(&other.deque)
+    }
+}
+
+impl<T: Ord> Ord for BoundedDeque<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deque.cmp(&other.deque)
+    }
+}
+
+impl<T: Hash> Hash for BoundedDeque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deque.hash(state)
+    }
+}
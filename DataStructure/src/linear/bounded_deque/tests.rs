@@ -0,0 +1,107 @@
+use super::BoundedDeque;
+use crate::linear::test_utils::assert_clone_eq_hash;
+use std::collections::VecDeque;
+
+const DEQUE_ARRAY: [i32; 5] = [0, -1, 2, -3, 4];
+
+fn create_full_deque() -> BoundedDeque<i32> {
+    let mut deque = BoundedDeque::new(DEQUE_ARRAY.len());
+    for &item in DEQUE_ARRAY.iter() {
+        assert_eq!(deque.push_back(item), Ok(()));
+    }
+    deque
+}
+
+#[test]
+fn test_new() {
+    let deque = BoundedDeque::<i32>::new(3);
+    assert!(deque.is_empty());
+    assert!(!deque.is_full());
+    assert_eq!(deque.capacity(), 3);
+    assert_eq!(deque.length(), 0);
+}
+
+#[test]
+fn test_push_full() {
+    let mut deque = create_full_deque();
+    assert!(deque.is_full());
+    assert_eq!(deque.push_back(5), Err(5));
+    assert_eq!(deque.push_front(6), Err(6));
+    assert_eq!(format!("{}", deque), "Head -> [0, -1, 2, -3, 4] <- Tail");
+    assert_eq!(deque.pop_back(), Some(4));
+    assert_eq!(deque.push_front(6), Ok(()));
+    assert_eq!(format!("{}", deque), "Head -> [6, 0, -1, 2, -3] <- Tail");
+    assert_eq!(deque.deque.buffer_size(), DEQUE_ARRAY.len());
+}
+
+#[test]
+fn test_head_tail() {
+    let mut deque = create_full_deque();
+    assert_eq!(deque.head(), Some(&0));
+    assert_eq!(deque.tail(), Some(&4));
+    *deque.head_mut().unwrap() = 10;
+    *deque.tail_mut().unwrap() = 20;
+    assert_eq!(deque.pop_front(), Some(10));
+    assert_eq!(deque.pop_back(), Some(20));
+    let mut empty = BoundedDeque::<i32>::new(1);
+    assert_eq!(empty.head(), None);
+    assert_eq!(empty.tail(), None);
+}
+
+#[test]
+fn test_overwrite() {
+    let mut deque = create_full_deque();
+    assert_eq!(deque.push_back_overwrite(5), Some(0));
+    assert_eq!(deque.push_back_overwrite(6), Some(-1));
+    assert_eq!(format!("{}", deque), "Head -> [2, -3, 4, 5, 6] <- Tail");
+    assert_eq!(deque.push_front_overwrite(7), Some(6));
+    assert_eq!(format!("{}", deque), "Head -> [7, 2, -3, 4, 5] <- Tail");
+    deque.pop_front();
+    assert_eq!(deque.push_front_overwrite(8), None);
+    assert_eq!(deque.push_back_overwrite(9), Some(8));
+    assert_eq!(format!("{}", deque), "Head -> [2, -3, 4, 5, 9] <- Tail");
+}
+
+#[test]
+fn test_ring_log() {
+    // Only the last `capacity` lines are kept, in order, without reallocating
+    let mut log = BoundedDeque::new(4);
+    let mut expected = VecDeque::new();
+    for line in 0..50 {
+        let evicted = log.push_back_overwrite(line);
+        expected.push_back(line);
+        let expected_evicted = if expected.len() > 4 { expected.pop_front() } else { None };
+        assert_eq!(evicted, expected_evicted);
+        assert_eq!(log.deque.buffer_size(), 4);
+    }
+    let values: Vec<String> = expected.iter().map(|v| v.to_string()).collect();
+    assert_eq!(format!("{}", log), format!("Head -> [{}] <- Tail", values.join(", ")));
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut deque = BoundedDeque::new(0);
+    assert!(deque.is_full());
+    assert_eq!(deque.push_back("item"), Err("item"));
+    assert_eq!(deque.push_front_overwrite("item"), Some("item"));
+    assert_eq!(deque.push_back_overwrite("item"), Some("item"));
+    assert!(deque.is_empty());
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut deque = BoundedDeque::new(2);
+    assert_eq!(deque.push_back(()), Ok(()));
+    assert_eq!(deque.push_front(()), Ok(()));
+    assert_eq!(deque.push_back(()), Err(()));
+    assert_eq!(deque.push_back_overwrite(()), Some(()));
+    assert_eq!(deque.length(), 2);
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let deque = create_full_deque();
+    assert_clone_eq_hash(&deque, &create_full_deque(), |clone| {
+        assert_eq!(clone.push_back_overwrite(5), Some(0));
+    });
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::queue::Queue;
+
+#[cfg(test)]
+mod tests;
+
+/// [`Queue`] holding at most a fixed number of elements.
+///
+/// The buffer is allocated once at construction and never reallocated: pushing on a full queue
+/// hands the item back instead of growing.
+///
+/// ```
+/// use data_structure::linear::BoundedQueue;
+///
+/// let mut queue = BoundedQueue::new(2);
+/// assert_eq!(queue.push(1), Ok(()));
+/// assert_eq!(queue.push(2), Ok(()));
+/// assert_eq!(queue.push(3), Err(3));
+/// assert_eq!(queue.pop(), Some(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedQueue<T> {
+    queue: Queue<T>,
+    // Maximum length, see the linear module docs
+    capacity: usize,
+}
+
+impl<T> BoundedQueue<T> {
+    pub fn new(capacity: usize) -> BoundedQueue<T> {
+        BoundedQueue { queue: Queue::with_capacity(capacity), capacity }
+    }
+
+    pub fn length(&self) -> usize {
+        self.queue.length()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn is_full(&self) -> bool {
+        self.queue.length() == self.capacity
+    }
+
+    /// Push `item` at the tail of the queue, or give it back if the queue is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.queue.push(item);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.queue.pop()
+    }
+    pub fn head(&mut self) -> Option<&T> {
+        self.queue.head()
+    }
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        self.queue.head_mut()
+    }
+}
+
+impl<T: Display> Display for BoundedQueue<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.queue.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for BoundedQueue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.queue == other.queue
+    }
+}
+
+impl<T: Eq> Eq for BoundedQueue<T> {}
+
+impl<T: PartialOrd> PartialOrd for BoundedQueue<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.queue.partial_cmp(&other.queue)
+    }
+}
+
+impl<T: Ord> Ord for BoundedQueue<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.queue.cmp(&other.queue)
+    }
+}
+
+impl<T: Hash> Hash for BoundedQueue<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.queue.hash(state)
+    }
+}
@@ -0,0 +1,80 @@
+use super::BoundedQueue;
+use crate::linear::test_utils::assert_clone_eq_hash;
+
+const QUEUE_ARRAY: [i32; 5] = [0, -1, 2, -3, 4];
+
+fn create_full_queue() -> BoundedQueue<i32> {
+    let mut queue = BoundedQueue::new(QUEUE_ARRAY.len());
+    for &item in QUEUE_ARRAY.iter() {
+        assert_eq!(queue.push(item), Ok(()));
+    }
+    queue
+}
+
+#[test]
+fn test_new() {
+    let queue = BoundedQueue::<i32>::new(3);
+    assert!(queue.is_empty());
+    assert!(!queue.is_full());
+    assert_eq!(queue.capacity(), 3);
+    assert_eq!(queue.length(), 0);
+}
+
+#[test]
+fn test_push_full() {
+    let mut queue = create_full_queue();
+    assert!(queue.is_full());
+    assert_eq!(queue.push(5), Err(5));
+    assert_eq!(format!("{}", queue), "Head -> [0, -1, 2, -3, 4] <- Tail");
+    assert_eq!(queue.pop(), Some(0));
+    assert_eq!(queue.push(5), Ok(()));
+    assert_eq!(queue.push(6), Err(6));
+    assert_eq!(format!("{}", queue), "Head -> [-1, 2, -3, 4, 5] <- Tail");
+}
+
+#[test]
+fn test_never_reallocates() {
+    let mut queue = create_full_queue();
+    // Keep the queue full while wrapping around the buffer several times
+    let expected: Vec<i32> = QUEUE_ARRAY.iter().copied().chain(0..15).collect();
+    for (i, item) in expected.into_iter().enumerate() {
+        assert_eq!(queue.pop(), Some(item));
+        assert_eq!(queue.push(i as i32), Ok(()));
+        assert_eq!(queue.queue.buffer_size(), QUEUE_ARRAY.len());
+    }
+    assert_eq!(format!("{}", queue), "Head -> [15, 16, 17, 18, 19] <- Tail");
+}
+
+#[test]
+fn test_head() {
+    let mut queue = create_full_queue();
+    assert_eq!(queue.head(), Some(&0));
+    *queue.head_mut().unwrap() = 10;
+    assert_eq!(queue.pop(), Some(10));
+    let mut empty = BoundedQueue::<i32>::new(1);
+    assert_eq!(empty.head(), None);
+    assert_eq!(empty.pop(), None);
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut queue = BoundedQueue::new(0);
+    assert!(queue.is_full());
+    assert_eq!(queue.push("item"), Err("item"));
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut queue = BoundedQueue::new(1);
+    assert_eq!(queue.push(()), Ok(()));
+    assert_eq!(queue.push(()), Err(()));
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let queue = create_full_queue();
+    assert_clone_eq_hash(&queue, &create_full_queue(), |clone| {
+        assert_eq!(clone.push(5), Err(5));
+        clone.pop();
+    });
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use super::stack::Stack;
+
+#[cfg(test)]
+mod tests;
+
+/// [`Stack`] holding at most a fixed number of elements.
+///
+/// The buffer is allocated once at construction and never reallocated: pushing on a full stack
+/// hands the item back instead of growing.
+///
+/// ```
+/// use data_structure::linear::BoundedStack;
+///
+/// let mut stack = BoundedStack::new(2);
+/// assert_eq!(stack.push(1), Ok(()));
+/// assert_eq!(stack.push(2), Ok(()));
+/// assert_eq!(stack.push(3), Err(3));
+/// assert_eq!(stack.pop(), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedStack<T> {
+    stack: Stack<T>,
+    // Maximum length, see the linear module docs
+    capacity: usize,
+}
+
+impl<T> BoundedStack<T> {
+    pub fn new(capacity: usize) -> BoundedStack<T> {
+        BoundedStack { stack: Stack::with_capacity(capacity), capacity }
+    }
+
+    pub fn length(&self) -> usize {
+        self.stack.length()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    pub fn is_full(&self) -> bool {
+        self.stack.length() == self.capacity
+    }
+
+    /// Push `item` on top of the stack, or give it back if the stack is full
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.stack.push(item);
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+    pub fn top(&mut self) -> Option<&T> {
+        self.stack.top()
+    }
+    pub fn top_mut(&mut self) -> Option<&mut T> {
+        self.stack.top_mut()
+    }
+}
+
+impl<T: Display> Display for BoundedStack<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.stack.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for BoundedStack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.stack == other.stack
+    }
+}
+
+impl<T: Eq> Eq for BoundedStack<T> {}
+
+impl<T: PartialOrd> PartialOrd for BoundedStack<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.stack.partial_cmp(&other.stack)
+    }
+}
+
+impl<T: Ord> Ord for BoundedStack<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.stack.cmp(&other.stack)
+    }
+}
+
+impl<T: Hash> Hash for BoundedStack<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.stack.hash(state)
+    }
+}
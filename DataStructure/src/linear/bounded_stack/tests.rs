@@ -0,0 +1,87 @@
+use super::BoundedStack;
+use crate::linear::test_utils::assert_clone_eq_hash;
+
+const STACK_ARRAY: [i32; 5] = [0, -1, 2, -3, 4];
+
+fn create_full_stack() -> BoundedStack<i32> {
+    let mut stack = BoundedStack::new(STACK_ARRAY.len());
+    for &item in STACK_ARRAY.iter() {
+        assert_eq!(stack.push(item), Ok(()));
+    }
+    stack
+}
+
+#[test]
+fn test_new() {
+    let stack = BoundedStack::<i32>::new(3);
+    assert!(stack.is_empty());
+    assert!(!stack.is_full());
+    assert_eq!(stack.capacity(), 3);
+    assert_eq!(stack.length(), 0);
+}
+
+#[test]
+fn test_push_full() {
+    let mut stack = create_full_stack();
+    assert!(stack.is_full());
+    assert_eq!(stack.push(5), Err(5));
+    assert_eq!(stack.length(), STACK_ARRAY.len());
+    assert_eq!(stack.stack.buffer_size(), STACK_ARRAY.len());
+    assert_eq!(format!("{}", stack), "Top -> [4, -3, 2, -1, 0]");
+    assert_eq!(stack.pop(), Some(4));
+    assert_eq!(stack.push(5), Ok(()));
+    assert_eq!(stack.top(), Some(&5));
+}
+
+#[test]
+fn test_pop() {
+    let mut stack = create_full_stack();
+    for &item in STACK_ARRAY.iter().rev() {
+        assert_eq!(stack.pop(), Some(item));
+    }
+    assert_eq!(stack.pop(), None);
+    assert_eq!(stack.top(), None);
+}
+
+#[test]
+fn test_top_mut() {
+    let mut stack = create_full_stack();
+    *stack.top_mut().unwrap() = 10;
+    assert_eq!(stack.pop(), Some(10));
+}
+
+#[test]
+fn test_zero_capacity() {
+    let mut stack = BoundedStack::new(0);
+    assert!(stack.is_full());
+    assert_eq!(stack.push("item"), Err("item"));
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut stack = BoundedStack::new(2);
+    assert_eq!(stack.push(()), Ok(()));
+    assert_eq!(stack.push(()), Ok(()));
+    assert_eq!(stack.push(()), Err(()));
+    assert_eq!(stack.length(), 2);
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let stack = create_full_stack();
+    assert_clone_eq_hash(&stack, &create_full_stack(), |clone| {
+        assert_eq!(clone.push(5), Err(5));
+        clone.pop();
+    });
+    assert_eq!(stack.clone().capacity(), stack.capacity());
+}
+
+#[test]
+fn test_debug_and_ord() {
+    let stack = create_full_stack();
+    assert_eq!(format!("{:?}", stack), "BoundedStack { stack: [4, -3, 2, -1, 0], capacity: 5 }");
+    let mut other = create_full_stack();
+    other.pop();
+    assert!(other < stack);
+}
@@ -1,60 +1,80 @@
-use std::alloc::{alloc, dealloc, Layout};
-use std::fmt::{Display, Formatter};
-use std::mem::MaybeUninit;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut, RangeBounds};
 use std::ptr;
-use std::ptr::NonNull;
+use std::slice;
 
-use super::buffer_guard::BufferGuard;
+use crate::error::{Error, Result};
+use super::growth_policy::GrowthPolicy;
+use super::range;
+use super::raw_buffer::RawBuffer;
+use super::ring_buffer::RingBuffer;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug)]
+pub use super::ring_iter::{Drain, IntoIter, Iter, IterMut};
+
 pub struct Deque<T> {
-    size: usize,
     length: usize,
-    buffer: NonNull<T>,
-    layout: Layout,
-    // Indexes for circular array (and reduce most ops to O(1))
-    head_index: usize,
-    tail_index: usize,
+    // Circular array, to reduce most ops to O(1)
+    ring: RingBuffer<T>,
+    policy: GrowthPolicy,
 }
 
 pub const DEQUE_START_SIZE: usize = 10;
 pub const DEQUE_SIZE_INCREMENT: usize = 10;
 
-impl<T: Clone> Deque<T> {
+impl<T> Deque<T> {
     pub fn new() -> Deque<T> {
-        let layout = Layout::array::<T>(DEQUE_START_SIZE).expect("Layout error");
-        let ptr = NonNull::new(unsafe { alloc(layout) } as *mut T).expect("Allocation failed");
-        Deque {
-            size: DEQUE_START_SIZE,
-            length: 0,
-            buffer: ptr,
-            layout,
-            head_index: 0,
-            tail_index: 0,
-        }
+        Self::with_capacity_and_policy(DEQUE_START_SIZE, GrowthPolicy::Linear(DEQUE_SIZE_INCREMENT))
     }
 
-    fn _increment_size(&mut self, increment: usize) {
-        let new_size = self.size + increment;
-        // Create a temporary new buffer that will be correctly dropped if something goes wrong
-        let buffer_guard = BufferGuard::<T>::new(new_size);
-        for i in 0..self.length {
-            unsafe {
-                ptr::write(
-                    buffer_guard.as_ptr().add(i),
-                    ptr::read(self.buffer.as_ptr().add((self.head_index + i) % self.length)),
-                )
-            };
-        }
-        // Deallocate the old buffer and replace it with the new in the buffer guard
-        unsafe { dealloc(self.buffer.as_ptr() as *mut u8, self.layout) };
-        (self.buffer, self.layout) = buffer_guard.into_inner();
-        self.size = new_size;
-        self.head_index = 0;
-        if self.length > 0 { self.length - 1 } else { 0 };
+    /// Create an empty deque with room for `capacity` elements, growing according to `policy`
+    /// once full. [`new`](Self::new) is equivalent to a capacity of `DEQUE_START_SIZE` and a
+    /// [`GrowthPolicy::Linear`] policy of `DEQUE_SIZE_INCREMENT` slots.
+    pub fn with_capacity_and_policy(capacity: usize, policy: GrowthPolicy) -> Deque<T> {
+        Deque { length: 0, ring: RingBuffer::new(capacity), policy }
+    }
+
+    /// Create an empty deque with room for `capacity` elements, growing by
+    /// `DEQUE_SIZE_INCREMENT` slots once full
+    pub fn with_capacity(capacity: usize) -> Deque<T> {
+        Self::with_capacity_and_policy(capacity, GrowthPolicy::Linear(DEQUE_SIZE_INCREMENT))
+    }
+
+    /// Make room for at least `additional` more elements, like
+    /// [`Stack::reserve`](super::Stack::reserve) does.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Make room for exactly `additional` more elements, like
+    /// [`Stack::reserve_exact`](super::Stack::reserve_exact) does.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Fallible [`reserve`](Self::reserve): on error the deque is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        self.ring.try_reserve(&self.policy, self.length, additional)
+    }
+
+    /// Fallible [`reserve_exact`](Self::reserve_exact): on error the deque is left unchanged.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<()> {
+        self.ring.try_reserve_exact(self.length, additional)
+    }
+
+    /// Release the buffer slots beyond `min_capacity`, keeping at least room for the elements
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.ring.shrink_to(min_capacity, self.length);
+    }
+
+    /// Release every unused buffer slot
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
     }
 
     pub fn length(&self) -> usize {
@@ -64,59 +84,50 @@ impl<T: Clone> Deque<T> {
         self.length == 0
     }
     pub fn buffer_size(&self) -> usize {
-        self.size
+        self.ring.buffer.capacity()
     }
     pub fn buffer_is_full(&self) -> bool {
-        self.size == self.length
+        self.ring.buffer.capacity() == self.length
+    }
+    pub fn growth_policy(&self) -> &GrowthPolicy {
+        &self.policy
     }
 
     pub fn push_front(&mut self, item: T) {
         if self.buffer_is_full() {
-            self._increment_size(DEQUE_SIZE_INCREMENT);
+            self.ring.grow(&self.policy, self.length);
         }
+        self.ring.head = self.ring.buffer.wrap_sub(self.ring.head, 1);
         unsafe {
-            let mut new_head_index = self.head_index;
-            if self.length != 0 {
-                new_head_index = if self.head_index == 0 { self.size - 1 } else { self.head_index - 1 };
-            }
-            *self.buffer.as_ptr().add(new_head_index) = item;
-            self.head_index = new_head_index;
+            ptr::write(self.ring.buffer.ptr(self.ring.head), item);
         }
         self.length += 1;
     }
     pub fn push_back(&mut self, item: T) {
         if self.buffer_is_full() {
-            self._increment_size(DEQUE_SIZE_INCREMENT);
+            self.ring.grow(&self.policy, self.length);
         }
         unsafe {
-            let mut new_tail_index = self.tail_index;
-            if self.length != 0 {
-                new_tail_index = (self.tail_index + 1) % self.size;
-            }
-            *self.buffer.as_ptr().add(new_tail_index) = item;
-            self.tail_index = new_tail_index;
+            ptr::write(self.ring.buffer.ptr(self.ring.tail), item);
         }
+        self.ring.tail = self.ring.buffer.wrap_add(self.ring.tail, 1);
         self.length += 1;
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
         if self.length > 0 {
-            let to_return = unsafe { ptr::read(self.buffer.as_ptr().add(self.head_index)) };
+            let to_return = unsafe { ptr::read(self.ring.buffer.ptr(self.ring.head)) };
+            self.ring.head = self.ring.buffer.wrap_add(self.ring.head, 1);
             self.length -= 1;
-            if self.length != 0 {
-                self.head_index = (self.head_index + 1) % self.size;
-            }
             return Some(to_return);
         }
         None
     }
     pub fn pop_back(&mut self) -> Option<T> {
         if self.length > 0 {
-            let to_return = unsafe { ptr::read(self.buffer.as_ptr().add(self.tail_index)) };
+            self.ring.tail = self.ring.buffer.wrap_sub(self.ring.tail, 1);
+            let to_return = unsafe { ptr::read(self.ring.buffer.ptr(self.ring.tail)) };
             self.length -= 1;
-            if self.length != 0 {
-                self.tail_index = if self.tail_index == 0 { self.size - 1 } else { self.tail_index - 1 };
-            }
             return Some(to_return);
         }
         None
@@ -124,52 +135,615 @@ impl<T: Clone> Deque<T> {
 
     pub fn head(&mut self) -> Option<&T> {
         if self.length > 0 {
-            return Some(unsafe { &*self.buffer.as_ptr().add(self.head_index) });
+            return Some(unsafe { &*self.slot(0) });
         }
         None
     }
     pub fn head_mut(&mut self) -> Option<&mut T> {
         if self.length > 0 {
-            return Some(unsafe { &mut *self.buffer.as_ptr().add(self.head_index) });
+            return Some(unsafe { &mut *self.slot(0) });
         }
         None
     }
     pub fn tail(&mut self) -> Option<&T> {
         if self.length > 0 {
-            return Some(unsafe { &*self.buffer.as_ptr().add(self.tail_index) });
+            return Some(unsafe { &*self.slot(self.length - 1) });
         }
         None
     }
     pub fn tail_mut(&mut self) -> Option<&mut T> {
         if self.length > 0 {
-            return Some(unsafe { &mut *self.buffer.as_ptr().add(self.tail_index) });
+            return Some(unsafe { &mut *self.slot(self.length - 1) });
         }
         None
     }
+
+    /// [`pop_front`](Self::pop_front) failing with [`Error::Empty`] on an empty deque
+    pub fn try_pop_front(&mut self) -> Result<T> {
+        self.pop_front().ok_or(Error::Empty)
+    }
+
+    /// [`pop_back`](Self::pop_back) failing with [`Error::Empty`] on an empty deque
+    pub fn try_pop_back(&mut self) -> Result<T> {
+        self.pop_back().ok_or(Error::Empty)
+    }
+
+    /// [`head`](Self::head) failing with [`Error::Empty`] on an empty deque
+    pub fn get_head(&self) -> Result<&T> {
+        self.get(0).map_err(|_| Error::Empty)
+    }
+
+    /// [`head_mut`](Self::head_mut) failing with [`Error::Empty`] on an empty deque
+    pub fn get_head_mut(&mut self) -> Result<&mut T> {
+        self.get_mut(0).map_err(|_| Error::Empty)
+    }
+
+    /// [`tail`](Self::tail) failing with [`Error::Empty`] on an empty deque
+    pub fn get_tail(&self) -> Result<&T> {
+        let index = self.length.checked_sub(1).ok_or(Error::Empty)?;
+        self.get(index)
+    }
+
+    /// [`tail_mut`](Self::tail_mut) failing with [`Error::Empty`] on an empty deque
+    pub fn get_tail_mut(&mut self) -> Result<&mut T> {
+        let index = self.length.checked_sub(1).ok_or(Error::Empty)?;
+        self.get_mut(index)
+    }
+
+    /// Reference to the `index`-th element, counting from the head
+    pub fn get(&self, index: usize) -> Result<&T> {
+        if index >= self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
+        }
+        Ok(unsafe { &*self.slot(index) })
+    }
+
+    /// Mutable reference to the `index`-th element, counting from the head
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T> {
+        if index >= self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
+        }
+        Ok(unsafe { &mut *self.slot(index) })
+    }
+
+    /// Swap the elements at positions `i` and `j` from the head
+    ///
+    /// # Panics
+    ///
+    /// Panics if either position is out of bounds.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        for index in [i, j] {
+            if index >= self.length {
+                Error::IndexOutOfBounds { index, len: self.length }.raise();
+            }
+        }
+        unsafe { ptr::swap(self.slot(i), self.slot(j)) };
+    }
+
+    /// The elements as two slices: the ones from the head up to the end of the buffer, then the
+    /// ones wrapped around to its start. The second slice is empty if the deque is contiguous.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.ring.buffer.slice_ptrs(self.ring.head, self.length);
+        unsafe { (&*front, &*back) }
+    }
+
+    /// Mutable version of [`as_slices`](Self::as_slices)
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.ring.buffer.slice_ptrs(self.ring.head, self.length);
+        unsafe { (&mut *front, &mut *back) }
+    }
+
+    /// Move the elements in place so that they lie in a single slice, from the head to the
+    /// tail, and return it. Nothing moves if they already do, otherwise it takes time
+    /// proportional to the buffer size.
+    ///
+    /// ```
+    /// use data_structure::linear::Deque;
+    ///
+    /// let mut deque = Deque::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.as_slices().1, [2, 3]);
+    /// assert_eq!(deque.make_contiguous(), [1, 2, 3]);
+    /// assert_eq!(deque.as_slices(), (&[1, 2, 3][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.length > self.ring.buffer.capacity() - self.ring.head {
+            // Rotating the whole buffer brings the head to the first slot and the wrapped
+            // elements right after the ones before the end. Free slots are moved along as
+            // uninitialized values, never read nor dropped.
+            let capacity = self.ring.buffer.capacity();
+            let slots = self.ring.buffer.ptr(0) as *mut MaybeUninit<T>;
+            unsafe { slice::from_raw_parts_mut(slots, capacity) }.rotate_left(self.ring.head);
+            self.ring.head = 0;
+            self.ring.tail = self.ring.buffer.wrap_add(0, self.length);
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Rotate the deque `n` places to the left: the `n` elements at the head move to the tail,
+    /// in the same order. Takes time proportional to the smallest of `n` and `length - n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length.
+    pub fn rotate_left(&mut self, n: usize) {
+        if n > self.length {
+            Error::IndexOutOfBounds { index: n, len: self.length }.raise();
+        }
+        let k = self.length - n;
+        if n <= k { self._move_head_to_tail(n) } else { self._move_tail_to_head(k) }
+    }
+
+    /// Rotate the deque `n` places to the right: the `n` elements at the tail move to the head,
+    /// in the same order. Takes time proportional to the smallest of `n` and `length - n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length.
+    pub fn rotate_right(&mut self, n: usize) {
+        if n > self.length {
+            Error::IndexOutOfBounds { index: n, len: self.length }.raise();
+        }
+        let k = self.length - n;
+        if n <= k { self._move_tail_to_head(n) } else { self._move_head_to_tail(k) }
+    }
+
+    /// Move `count` elements one at a time from the head to the free slot after the tail. When
+    /// the buffer is full both are the same slot, and the indexes just move along.
+    fn _move_head_to_tail(&mut self, count: usize) {
+        for _ in 0..count {
+            let buffer = &self.ring.buffer;
+            let (from, to) = (buffer.ptr(self.ring.head), buffer.ptr(self.ring.tail));
+            unsafe { ptr::copy(from, to, 1) };
+            self.ring.head = self.ring.buffer.wrap_add(self.ring.head, 1);
+            self.ring.tail = self.ring.buffer.wrap_add(self.ring.tail, 1);
+        }
+    }
+
+    /// Move `count` elements one at a time from the tail to the free slot before the head
+    fn _move_tail_to_head(&mut self, count: usize) {
+        for _ in 0..count {
+            self.ring.head = self.ring.buffer.wrap_sub(self.ring.head, 1);
+            self.ring.tail = self.ring.buffer.wrap_sub(self.ring.tail, 1);
+            let buffer = &self.ring.buffer;
+            let (from, to) = (buffer.ptr(self.ring.tail), buffer.ptr(self.ring.head));
+            unsafe { ptr::copy(from, to, 1) };
+        }
+    }
+
+    /// Binary search over a deque sorted according to `f`, that returns how an element
+    /// compares to the target. Like [`slice::binary_search_by`], returns `Ok` with the position
+    /// of a matching element, or `Err` with the position where the target could be inserted
+    /// while keeping the deque sorted.
+    ///
+    /// ```
+    /// use data_structure::linear::Deque;
+    ///
+    /// let mut deque = Deque::from([3, 5, 8]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.binary_search_by(|item| item.cmp(&5)), Ok(2));
+    /// assert_eq!(deque.binary_search_by(|item| item.cmp(&4)), Err(2));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> std::result::Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (front, back) = self.as_slices();
+        // The wrapped part holds the greatest elements: search it if the target is not before
+        match back.first().map(&mut f) {
+            Some(Ordering::Equal) => Ok(front.len()),
+            Some(Ordering::Less) => {
+                let offset = front.len() + 1;
+                back[1..].binary_search_by(f).map(|i| i + offset).map_err(|i| i + offset)
+            }
+            _ => front.binary_search_by(f),
+        }
+    }
+
+    /// Insert `item` at position `index` from the head, shifting the elements between it and
+    /// the closest end of the deque. The index goes last, like with
+    /// [`DoubleLinkedList::insert`](super::DoubleLinkedList::insert).
+    pub fn insert(&mut self, item: T, index: usize) -> Result<()> {
+        if index > self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
+        }
+        if self.buffer_is_full() {
+            self.ring.grow(&self.policy, self.length);
+        }
+        if index < self.length - index {
+            // Elements before the index move one slot towards the head
+            self.ring.head = self.ring.buffer.wrap_sub(self.ring.head, 1);
+            for i in 0..index {
+                unsafe { ptr::copy(self.slot(i + 1), self.slot(i), 1) };
+            }
+        } else {
+            for i in (index..self.length).rev() {
+                unsafe { ptr::copy(self.slot(i), self.slot(i + 1), 1) };
+            }
+            self.ring.tail = self.ring.buffer.wrap_add(self.ring.tail, 1);
+        }
+        unsafe { ptr::write(self.slot(index), item) };
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Remove the element at position `index` from the head, shifting the elements between it
+    /// and the closest end of the deque
+    pub fn remove(&mut self, index: usize) -> Result<T> {
+        if index >= self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
+        }
+        let item = unsafe { ptr::read(self.slot(index)) };
+        if index < self.length - index - 1 {
+            // Elements before the index move one slot towards the tail
+            for i in (0..index).rev() {
+                unsafe { ptr::copy(self.slot(i), self.slot(i + 1), 1) };
+            }
+            self.ring.head = self.ring.buffer.wrap_add(self.ring.head, 1);
+        } else {
+            for i in index + 1..self.length {
+                unsafe { ptr::copy(self.slot(i), self.slot(i - 1), 1) };
+            }
+            self.ring.tail = self.ring.buffer.wrap_sub(self.ring.tail, 1);
+        }
+        self.length -= 1;
+        Ok(item)
+    }
+
+    /// Keep the first `length` elements from the head and drop the others. Nothing happens if
+    /// the deque is not longer than that.
+    pub fn truncate(&mut self, length: usize) {
+        if length >= self.length {
+            return;
+        }
+        let start = self.ring.buffer.wrap_add(self.ring.head, length);
+        let (front, back) = self.ring.buffer.slice_ptrs(start, self.length - length);
+        // Shortened first: if a drop panics, the elements left are leaked instead of exposed
+        self.length = length;
+        self.ring.tail = self.ring.buffer.wrap_add(self.ring.head, length);
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    /// Drop every element, keeping the buffer
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.ring.head = 0;
+        self.ring.tail = 0;
+    }
+
+    /// Keep only the elements for which `f` returns `true`, visiting them once from the head to
+    /// the tail. The elements kept stay in the same order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| f(item))
+    }
+
+    /// Same as [`retain`](Self::retain), giving `f` mutable references to the elements
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        /// Moves the elements not visited next to the kept ones, even if `f` or a drop panics
+        struct Compact<'a, T> {
+            deque: &'a mut Deque<T>,
+            visited: usize,
+            kept: usize,
+        }
+
+        impl<T> Drop for Compact<'_, T> {
+            fn drop(&mut self) {
+                let deque = &mut *self.deque;
+                let gap = self.visited - self.kept;
+                if gap > 0 {
+                    for i in self.visited..deque.length {
+                        unsafe { ptr::copy(deque.slot(i), deque.slot(i - gap), 1) };
+                    }
+                }
+                deque.length -= gap;
+                deque.ring.tail = deque.ring.buffer.wrap_add(deque.ring.head, deque.length);
+            }
+        }
+
+        let mut guard = Compact { deque: self, visited: 0, kept: 0 };
+        while guard.visited < guard.deque.length {
+            let slot = guard.deque.slot(guard.visited);
+            // If `f` panics the element has not been visited yet, and is kept
+            let keep = f(unsafe { &mut *slot });
+            guard.visited += 1;
+            if keep {
+                if guard.kept != guard.visited - 1 {
+                    unsafe { ptr::copy_nonoverlapping(slot, guard.deque.slot(guard.kept), 1) };
+                }
+                guard.kept += 1;
+            } else {
+                unsafe { ptr::drop_in_place(slot) };
+            }
+        }
+    }
+
+    /// Move every element of `other` after the tail, in the same order, leaving `other` empty
+    pub fn append(&mut self, other: &mut Deque<T>) {
+        self.reserve(other.length);
+        let (front, back) = other.ring.buffer.slice_ptrs(other.ring.head, other.length);
+        unsafe {
+            self._copy_after_tail(front);
+            self._copy_after_tail(back);
+        }
+        other.length = 0;
+        other.ring.head = 0;
+        other.ring.tail = 0;
+    }
+
+    /// Bitwise copy the elements of `items` after the tail, that must have room for them
+    unsafe fn _copy_after_tail(&mut self, items: *mut [T]) {
+        // Up to the end of the buffer, then from its start
+        let (count, items) = (items.len(), items as *const T);
+        let first = count.min(self.ring.buffer.capacity() - self.ring.tail);
+        ptr::copy_nonoverlapping(items, self.ring.buffer.ptr(self.ring.tail), first);
+        ptr::copy_nonoverlapping(items.add(first), self.ring.buffer.ptr(0), count - first);
+        self.length += count;
+        self.ring.tail = self.ring.buffer.wrap_add(self.ring.tail, count);
+    }
+
+    /// Split the deque in two at position `at` from the head: the elements from `at` on are
+    /// moved to the returned deque, that has the same growth policy
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> Deque<T> {
+        if at > self.length {
+            Error::IndexOutOfBounds { index: at, len: self.length }.raise();
+        }
+        let count = self.length - at;
+        let mut other = Deque::with_capacity_and_policy(count, self.policy.clone());
+        let start = self.ring.buffer.wrap_add(self.ring.head, at);
+        let (front, back) = self.ring.buffer.slice_ptrs(start, count);
+        unsafe {
+            other._copy_after_tail(front);
+            other._copy_after_tail(back);
+        }
+        self.length = at;
+        self.ring.tail = self.ring.buffer.wrap_add(self.ring.head, at);
+        other
+    }
+
+    /// Resize the deque to `length` elements, truncating it or pushing values returned by `f`
+    /// after the tail
+    pub fn resize_with<F>(&mut self, length: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if length <= self.length {
+            self.truncate(length);
+            return;
+        }
+        self.reserve(length - self.length);
+        while self.length < length {
+            self.push_back(f());
+        }
+    }
+
+    /// Iterate over the elements from the head to the tail
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.ring.buffer, self.ring.head, self.length)
+    }
+
+    /// Iterate mutably over the elements from the head to the tail
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&self.ring.buffer, self.ring.head, self.length)
+    }
+
+    /// Remove the elements in `range`, counting positions from the head, and iterate over them
+    /// from the head to the tail. Elements left in the range are removed when the iterator is
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range ends after the length or starts after its end.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = range::bounds(range, self.length);
+        Drain::new(
+            &mut self.ring.buffer,
+            self.ring.head,
+            &mut self.length,
+            &mut self.ring.tail,
+            range.start,
+            range.end,
+        )
+    }
+
+    /// Pointer to the buffer slot of the `index`-th element, counting from the head
+    fn slot(&self, index: usize) -> *mut T {
+        self.ring.buffer.ptr(self.ring.buffer.wrap_add(self.ring.head, index))
+    }
+}
+
+impl<T> Index<usize> for Deque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).unwrap_or_else(|error| error.raise())
+    }
+}
+
+impl<T> IndexMut<usize> for Deque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).unwrap_or_else(|error| error.raise())
+    }
 }
 
 impl<T: Display> Display for Deque<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Head -> [")?;
-        for i in 0..self.length {
-            write!(f, "{}", unsafe { self.buffer.as_ptr().add(i).read() })?;
-            if i != self.length - 1 {
+        for (i, item) in self.iter().enumerate() {
+            if i != 0 {
                 write!(f, ", ")?;
             }
+            write!(f, "{}", item)?;
         }
         write!(f, "] <- Tail")
     }
 }
 
+impl<T: Debug> Debug for Deque<T> {
+    /// Elements from the head to the tail
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for Deque<T> {
+    fn clone(&self) -> Self {
+        let mut clone = Deque {
+            length: 0,
+            ring: RingBuffer::new(self.ring.buffer.capacity()),
+            policy: self.policy.clone(),
+        };
+        // Elements are counted one at a time: if a clone panics the copied ones are dropped
+        for item in self.iter() {
+            clone.push_back(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T: PartialEq> PartialEq for Deque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Deque<T> {}
+
+impl<T: PartialOrd> PartialOrd for Deque<T> {
+    /// Lexicographic comparison of the elements from the head to the tail
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for Deque<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for Deque<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consume the deque, yielding its elements from the head to the tail
+    fn into_iter(mut self) -> IntoIter<T> {
+        // The deque keeps an empty buffer: its drop has nothing left to do
+        let length = mem::replace(&mut self.length, 0);
+        let head_index = mem::replace(&mut self.ring.head, 0);
+        self.ring.tail = 0;
+        let buffer = mem::replace(&mut self.ring.buffer, RawBuffer::new(0));
+        IntoIter::new(buffer, head_index, length)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Deque<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Deque<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Deque::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T> Extend<T> for Deque<T> {
+    /// Push the items after the tail, in order
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for Deque<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T> From<Vec<T>> for Deque<T> {
+    /// Take over the vector buffer without copying: its first element is the head
+    fn from(vec: Vec<T>) -> Self {
+        Deque {
+            length: vec.len(),
+            ring: RingBuffer::from_vec(vec),
+            policy: GrowthPolicy::Linear(DEQUE_SIZE_INCREMENT),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Deque<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T> From<Deque<T>> for Vec<T> {
+    /// Hand the deque buffer over, elements going from the head to the tail. Only a deque
+    /// wrapped around the end of its buffer needs a new allocation to be unrolled.
+    fn from(mut deque: Deque<T>) -> Self {
+        let length = mem::replace(&mut deque.length, 0);
+        let head_index = mem::replace(&mut deque.ring.head, 0);
+        deque.ring.tail = 0;
+        let buffer = mem::replace(&mut deque.ring.buffer, RawBuffer::new(0));
+        buffer.into_vec(head_index, length)
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for Deque<T> {
     fn drop(&mut self) {
+        // Drop every element to ensure custom and deep drops are executed, the buffer array is
+        // deallocated by its own drop
+        let (front, back) = self.ring.buffer.slice_ptrs(self.ring.head, self.length);
         unsafe {
-            // Drop every element to ensure custom and deep drops are executed
-            for i in 0..self.length {
-                ptr::drop_in_place(self.buffer.as_ptr().add(i));
-            }
-            // Drop the buffer array
-            dealloc(self.buffer.as_ptr() as _, self.layout);
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
         }
     }
 }
@@ -1,12 +1,18 @@
 use super::{Deque, DEQUE_START_SIZE, DEQUE_SIZE_INCREMENT};
+use crate::error::Error;
+use crate::linear::GrowthPolicy;
+use crate::linear::test_utils::assert_clone_eq_hash;
+use std::sync::Arc;
+use std::collections::VecDeque;
+use std::rc::Rc;
 
 const DEQUE_ARRAY: [i32; 9] = [0, -1, 2, -3, 4, -5, 6, -7, 8];
-const DEQUE_DISPLAY: &'static str = "Head -> [0, -1, 2, -3, 4, -5, 6, -7, 8] <- Tail";
+const DEQUE_DISPLAY: &str = "Head -> [0, -1, 2, -3, 4, -5, 6, -7, 8] <- Tail";
 
 fn create_deque() -> Deque<i32> {
     let mut deque = Deque::<i32>::new();
-    for i in 0..DEQUE_ARRAY.len() {
-        deque.push_back(DEQUE_ARRAY[i]);
+    for &item in DEQUE_ARRAY.iter() {
+        deque.push_back(item);
     }
     deque
 }
@@ -54,10 +60,10 @@ fn test_push_front() {
     let mut deque = Deque::<i32>::new();
     assert!(deque.head().is_none());
     assert!(deque.head_mut().is_none());
-    for i in 0..DEQUE_ARRAY.len() {
-        deque.push_front(DEQUE_ARRAY[i]);
+    for (i, &item) in DEQUE_ARRAY.iter().enumerate() {
+        deque.push_front(item);
         assert_eq!(deque.length(), i + 1);
-        assert_eq!(*deque.head().unwrap(), DEQUE_ARRAY[i]);
+        assert_eq!(*deque.head().unwrap(), item);
         assert_eq!(*deque.tail().unwrap(), DEQUE_ARRAY[0]);
     }
 }
@@ -67,10 +73,10 @@ fn test_push_back() {
     let mut deque = Deque::<i32>::new();
     assert!(deque.tail().is_none());
     assert!(deque.tail_mut().is_none());
-    for i in 0..DEQUE_ARRAY.len() {
-        deque.push_back(DEQUE_ARRAY[i]);
+    for (i, &item) in DEQUE_ARRAY.iter().enumerate() {
+        deque.push_back(item);
         assert_eq!(deque.length(), i + 1);
-        assert_eq!(*deque.tail().unwrap(), DEQUE_ARRAY[i]);
+        assert_eq!(*deque.tail().unwrap(), item);
         assert_eq!(*deque.head().unwrap(), DEQUE_ARRAY[0]);
     }
 }
@@ -78,17 +84,34 @@ fn test_push_back() {
 #[test]
 fn test_head() {
     let mut deque = create_deque();
-    let mut head = deque.head_mut().unwrap();
+    let head = deque.head_mut().unwrap();
     assert_eq!(*head, 0);
     *head = 5000;
     assert_eq!(*deque.head().unwrap(), 5000);
     assert_eq!(*deque.head_mut().unwrap(), 5000);
 }
 
+#[test]
+fn test_empty_errors() {
+    fn pop_ends(deque: &mut Deque<i32>) -> Result<(i32, i32), Error> {
+        Ok((deque.try_pop_front()?, deque.try_pop_back()?))
+    }
+    let mut deque = create_deque();
+    assert_eq!((deque.get_head(), deque.get_tail()), (Ok(&0), Ok(&8)));
+    *deque.get_head_mut().unwrap() = 9;
+    *deque.get_tail_mut().unwrap() = 10;
+    assert_eq!(pop_ends(&mut deque), Ok((9, 10)));
+    deque.clear();
+    assert_eq!(pop_ends(&mut deque), Err(Error::Empty));
+    assert_eq!((deque.get_head(), deque.get_tail()), (Err(Error::Empty), Err(Error::Empty)));
+    assert_eq!(deque.get_head_mut(), Err(Error::Empty));
+    assert_eq!(deque.get_tail_mut(), Err(Error::Empty));
+}
+
 #[test]
 fn test_tail() {
     let mut deque = create_deque();
-    let mut tail = deque.tail_mut().unwrap();
+    let tail = deque.tail_mut().unwrap();
     assert_eq!(*tail, 8);
     *tail = 5000;
     assert_eq!(*deque.tail().unwrap(), 5000);
@@ -110,7 +133,565 @@ fn test_increment_buffer() {
 
 #[test]
 fn test_display() {
-    let mut deque = create_deque();
+    let deque = create_deque();
     let s = format!("{}", deque);
     assert_eq!(s, DEQUE_DISPLAY.to_string());
 }
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    PushFront,
+    PushBack,
+    PopFront,
+    PopBack,
+}
+
+const OPS: [Op; 4] = [Op::PushFront, Op::PushBack, Op::PopFront, Op::PopBack];
+const OPS_SEQUENCE_LENGTH: u32 = 5;
+
+fn apply(deque: &mut Deque<i32>, expected: &mut VecDeque<i32>, op: Op, value: i32) {
+    match op {
+        Op::PushFront => {
+            deque.push_front(value);
+            expected.push_front(value);
+        }
+        Op::PushBack => {
+            deque.push_back(value);
+            expected.push_back(value);
+        }
+        Op::PopFront => assert_eq!(deque.pop_front(), expected.pop_front()),
+        Op::PopBack => assert_eq!(deque.pop_back(), expected.pop_back()),
+    }
+}
+
+fn assert_same(deque: &mut Deque<i32>, expected: &VecDeque<i32>) {
+    assert_eq!(deque.length(), expected.len());
+    assert_eq!(deque.head().copied(), expected.front().copied());
+    assert_eq!(deque.tail().copied(), expected.back().copied());
+    let values: Vec<String> = expected.iter().map(|v| v.to_string()).collect();
+    assert_eq!(format!("{}", deque), format!("Head -> [{}] <- Tail", values.join(", ")));
+}
+
+/// Build a deque whose head sits at buffer slot `offset` and holding `fill` elements, pushed
+/// alternately to the front and to the back
+fn create_rotated_deque(offset: usize, fill: usize) -> (Deque<i32>, VecDeque<i32>) {
+    let mut deque = Deque::new();
+    let mut expected = VecDeque::new();
+    for i in 0..offset {
+        deque.push_back(i as i32);
+        assert_eq!(deque.pop_front(), Some(i as i32));
+    }
+    for i in 0..fill {
+        let op = if i % 2 == 0 { Op::PushBack } else { Op::PushFront };
+        apply(&mut deque, &mut expected, op, 100 + i as i32);
+    }
+    (deque, expected)
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_interleaved_ops_against_vec_deque() {
+    let sequences = OPS.len().pow(OPS_SEQUENCE_LENGTH);
+    for offset in 0..DEQUE_START_SIZE {
+        for fill in 0..=DEQUE_START_SIZE {
+            for sequence in 0..sequences {
+                let (mut deque, mut expected) = create_rotated_deque(offset, fill);
+                let mut code = sequence;
+                for step in 0..OPS_SEQUENCE_LENGTH {
+                    let op = OPS[code % OPS.len()];
+                    code /= OPS.len();
+                    apply(&mut deque, &mut expected, op, step as i32);
+                    assert_same(&mut deque, &expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_increment_buffer_wrapped() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, mut expected) = create_rotated_deque(offset, DEQUE_START_SIZE);
+        assert!(deque.buffer_is_full());
+        apply(&mut deque, &mut expected, Op::PushFront, -1);
+        apply(&mut deque, &mut expected, Op::PushBack, -2);
+        assert_eq!(deque.buffer_size(), DEQUE_START_SIZE + DEQUE_SIZE_INCREMENT);
+        assert_same(&mut deque, &expected);
+        while !expected.is_empty() {
+            apply(&mut deque, &mut expected, Op::PopBack, 0);
+            apply(&mut deque, &mut expected, Op::PopFront, 0);
+            assert_same(&mut deque, &expected);
+        }
+    }
+}
+
+#[test]
+fn test_drop_wrapped() {
+    let item = Rc::new(0);
+    for offset in 0..DEQUE_START_SIZE {
+        for fill in 0..=DEQUE_START_SIZE + 1 {
+            let mut deque = Deque::new();
+            for _ in 0..offset {
+                deque.push_back(Rc::clone(&item));
+                deque.pop_front();
+            }
+            for i in 0..fill {
+                if i % 2 == 0 {
+                    deque.push_front(Rc::clone(&item));
+                } else {
+                    deque.push_back(Rc::clone(&item));
+                }
+            }
+            assert_eq!(Rc::strong_count(&item), fill + 1);
+            drop(deque);
+            assert_eq!(Rc::strong_count(&item), 1);
+        }
+    }
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let (deque, expected) = create_rotated_deque(7, DEQUE_START_SIZE);
+    // Same elements, but stored from the start of the buffer
+    let mut other = Deque::new();
+    for &item in expected.iter() {
+        other.push_back(item);
+    }
+    assert_clone_eq_hash(&deque, &other, |clone| clone.push_front(100));
+    assert_same(&mut deque.clone(), &expected);
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut deque = Deque::<()>::new();
+    assert_eq!(deque.buffer_size(), usize::MAX);
+    // Pushing to the front wraps the head around to the end of the usize::MAX long buffer
+    for i in 0..1000 {
+        deque.push_front(());
+        deque.push_back(());
+        assert_eq!(deque.pop_back(), Some(()));
+        assert_eq!(deque.length(), i + 1);
+    }
+    assert!(!deque.buffer_is_full());
+    assert_eq!(deque.buffer_size(), usize::MAX);
+    assert_eq!(deque.head(), Some(&()));
+    assert_eq!(deque.tail(), Some(&()));
+    assert!(deque.clone() == deque);
+    for _ in 0..1000 {
+        assert_eq!(deque.pop_front(), Some(()));
+    }
+    assert!(deque.pop_back().is_none());
+}
+
+#[test]
+fn test_with_capacity_and_policy() {
+    let mut deque = Deque::with_capacity_and_policy(3, GrowthPolicy::Custom(Arc::new(|size| size * 3)));
+    for &item in DEQUE_ARRAY.iter() {
+        deque.push_front(item);
+    }
+    assert_eq!(deque.buffer_size(), 9);
+    deque.push_back(10);
+    assert_eq!(deque.buffer_size(), 27);
+    assert_eq!(format!("{}", deque), "Head -> [8, -7, 6, -5, 4, -3, 2, -1, 0, 10] <- Tail");
+    assert!(matches!(Deque::<i32>::new().growth_policy(), GrowthPolicy::Linear(DEQUE_SIZE_INCREMENT)));
+}
+
+#[test]
+fn test_reserve_and_shrink() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, mut expected) = create_rotated_deque(offset, 7);
+        deque.shrink_to_fit();
+        assert_eq!(deque.buffer_size(), 7);
+        assert_same(&mut deque, &expected);
+        apply(&mut deque, &mut expected, Op::PushFront, -1);
+        assert_same(&mut deque, &expected);
+        deque.reserve(20);
+        assert_eq!(deque.buffer_size(), 28);
+        deque.shrink_to(12);
+        assert_eq!(deque.buffer_size(), 12);
+        apply(&mut deque, &mut expected, Op::PopFront, 0);
+        apply(&mut deque, &mut expected, Op::PushBack, -2);
+        assert_same(&mut deque, &expected);
+        deque.reserve_exact(5);
+        assert_eq!(deque.buffer_size(), 13);
+        assert_same(&mut deque, &expected);
+    }
+}
+
+#[test]
+fn test_try_reserve() {
+    let (mut deque, expected) = create_rotated_deque(4, 9);
+    assert_eq!(deque.try_reserve(usize::MAX), Err(Error::CapacityExceeded));
+    assert_eq!(deque.try_reserve_exact(usize::MAX / 4), Err(Error::CapacityExceeded));
+    assert_eq!(deque.buffer_size(), DEQUE_START_SIZE);
+    assert_same(&mut deque, &expected);
+    assert_eq!(Deque::<i32>::with_capacity(0).buffer_size(), 0);
+}
+
+#[test]
+fn test_iter() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, expected) = create_rotated_deque(offset, 8);
+        assert!(deque.iter().eq(expected.iter()));
+        assert!(deque.iter().rev().eq(expected.iter().rev()));
+        assert_eq!(deque.iter().len(), expected.len());
+        for item in &mut deque {
+            *item += 1;
+        }
+        assert!((&deque).into_iter().copied().eq(expected.iter().map(|item| item + 1)));
+        let mut iter = deque.iter_mut();
+        *iter.next_back().unwrap() = 0;
+        assert_eq!(deque.tail(), Some(&0));
+    }
+}
+
+#[test]
+fn test_into_iter() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (deque, expected) = create_rotated_deque(offset, 8);
+        assert!(deque.into_iter().eq(expected.clone()));
+        let (deque, mut expected) = create_rotated_deque(offset, 8);
+        let mut iter = deque.into_iter();
+        assert_eq!(iter.next_back(), expected.pop_back());
+        assert_eq!(iter.next(), expected.pop_front());
+        assert_eq!(iter.len(), expected.len());
+        assert!(iter.rev().eq(expected.into_iter().rev()));
+    }
+}
+
+#[test]
+fn test_drain() {
+    let ranges = [(0, 0), (0, 3), (2, 5), (3, 9), (0, 9), (8, 9), (4, 4)];
+    for offset in 0..DEQUE_START_SIZE {
+        for (start, end) in ranges {
+            let (mut deque, mut expected) = create_rotated_deque(offset, 9);
+            assert!(deque.drain(start..end).eq(expected.drain(start..end)));
+            assert_same(&mut deque, &expected);
+            // Partially consumed from both ends
+            let (mut deque, mut expected) = create_rotated_deque(offset, 9);
+            let mut drain = deque.drain(start..end);
+            let mut expected_drain = expected.drain(start..end);
+            assert_eq!(drain.next(), expected_drain.next());
+            assert_eq!(drain.next_back(), expected_drain.next_back());
+            drop((drain, expected_drain));
+            assert_same(&mut deque, &expected);
+            apply(&mut deque, &mut expected, Op::PushBack, 100);
+            apply(&mut deque, &mut expected, Op::PushFront, 101);
+            assert_same(&mut deque, &expected);
+        }
+    }
+}
+
+#[test]
+fn test_from_iter_and_extend() {
+    let deque: Deque<i32> = DEQUE_ARRAY.into_iter().collect();
+    assert_eq!(format!("{}", deque), DEQUE_DISPLAY);
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, mut expected) = create_rotated_deque(offset, 8);
+        deque.extend(0..15);
+        expected.extend(0..15);
+        deque.extend(&[20, 21]);
+        expected.extend(&[20, 21]);
+        assert_same(&mut deque, &expected);
+    }
+}
+
+#[test]
+fn test_vec_conversions() {
+    let mut deque = Deque::from(DEQUE_ARRAY.to_vec());
+    assert_eq!(deque, create_deque());
+    // Pushing to either end grows the adopted buffer
+    deque.push_front(-1);
+    deque.push_back(9);
+    assert_eq!(deque.length(), DEQUE_ARRAY.len() + 2);
+    assert_eq!(Deque::from([1, 2, 3]).to_string(), "Head -> [1, 2, 3] <- Tail");
+    for offset in 0..DEQUE_START_SIZE {
+        let (deque, expected) = create_rotated_deque(offset, 9);
+        assert_eq!(Vec::from(deque), Vec::from(expected));
+    }
+    assert_eq!(Vec::from(Deque::<i32>::new()), []);
+}
+
+#[test]
+fn test_debug_and_ord() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (deque, expected) = create_rotated_deque(offset, 8);
+        assert_eq!(format!("{:?}", deque), format!("{:?}", expected));
+        let (mut other, mut other_expected) = create_rotated_deque(offset, 8);
+        other.push_front(104);
+        other_expected.push_front(104);
+        assert_eq!(other.cmp(&deque), other_expected.cmp(&expected));
+        assert_eq!(deque.partial_cmp(&other), expected.partial_cmp(&other_expected));
+    }
+    assert_eq!(Deque::<i32>::default(), Deque::new());
+}
+
+#[test]
+fn test_send_and_share() {
+    let deque = Arc::new(create_deque());
+    let shared = Arc::clone(&deque);
+    let handle = std::thread::spawn(move || shared.iter().copied().max());
+    assert_eq!(handle.join().unwrap(), Some(8));
+    let mut deque = Arc::try_unwrap(deque).unwrap();
+    let handle = std::thread::spawn(move || {
+        deque.push_front(-10);
+        deque
+    });
+    assert_eq!(handle.join().unwrap().pop_front(), Some(-10));
+}
+
+#[test]
+fn test_get_and_index() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, expected) = create_rotated_deque(offset, 8);
+        for (i, item) in expected.iter().enumerate() {
+            assert_eq!(deque.get(i), Ok(item));
+            assert_eq!(deque[i], *item);
+        }
+        *deque.get_mut(0).unwrap() = 1;
+        deque[7] *= 2;
+        assert_eq!((deque[0], deque[7]), (1, expected[7] * 2));
+        assert_eq!(deque.get(8), Err(Error::IndexOutOfBounds { index: 8, len: 8 }));
+        assert_eq!(deque.get_mut(9), Err(Error::IndexOutOfBounds { index: 9, len: 8 }));
+    }
+}
+
+#[test]
+#[should_panic(expected = "index 9 out of bounds (length is 9)")]
+fn test_index_out_of_bounds() {
+    let deque = create_deque();
+    let _ = deque[DEQUE_ARRAY.len()];
+}
+
+#[test]
+fn test_swap() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, mut expected) = create_rotated_deque(offset, 8);
+        for (i, j) in [(0, 7), (2, 5), (3, 3), (6, 1)] {
+            deque.swap(i, j);
+            expected.swap(i, j);
+        }
+        assert_same(&mut deque, &expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of bounds (length is 3)")]
+fn test_swap_out_of_bounds() {
+    Deque::from([1, 2, 3]).swap(0, 3);
+}
+
+#[test]
+fn test_slices() {
+    for offset in 0..DEQUE_START_SIZE {
+        for fill in [0, 1, 8, DEQUE_START_SIZE] {
+            let (mut deque, mut expected) = create_rotated_deque(offset, fill);
+            // Split where the buffer wraps, that may differ from VecDeque
+            let (front, back) = deque.as_slices();
+            assert_eq!(front.len(), fill.min(DEQUE_START_SIZE - deque.ring.head));
+            assert_eq!([front, back].concat(), Vec::from(expected.clone()));
+            let (front, back) = deque.as_mut_slices();
+            front.iter_mut().chain(back.iter_mut()).for_each(|item| *item += 1);
+            expected.iter_mut().for_each(|item| *item += 1);
+            assert_eq!(deque.make_contiguous(), expected.make_contiguous());
+            assert!(deque.as_slices().1.is_empty());
+            // The indexes follow the elements
+            apply(&mut deque, &mut expected, Op::PushFront, 1);
+            apply(&mut deque, &mut expected, Op::PushBack, 2);
+            assert_same(&mut deque, &expected);
+        }
+    }
+}
+
+#[test]
+fn test_rotate() {
+    for offset in 0..DEQUE_START_SIZE {
+        for fill in [8, DEQUE_START_SIZE] {
+            for n in 0..=fill {
+                let (mut deque, mut expected) = create_rotated_deque(offset, fill);
+                deque.rotate_left(n);
+                expected.rotate_left(n);
+                assert_same(&mut deque, &expected);
+                deque.rotate_right(fill - n / 2);
+                expected.rotate_right(fill - n / 2);
+                assert_same(&mut deque, &expected);
+                apply(&mut deque, &mut expected, Op::PushBack, 1);
+                apply(&mut deque, &mut expected, Op::PopFront, 0);
+                assert_same(&mut deque, &expected);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "index 4 out of bounds (length is 3)")]
+fn test_rotate_out_of_bounds() {
+    Deque::from([1, 2, 3]).rotate_right(4);
+}
+
+#[test]
+fn test_binary_search_by() {
+    for split in 0..=DEQUE_START_SIZE {
+        // Sorted values, the first `split` ones wrapped around the end of the buffer
+        let mut deque = Deque::new();
+        let mut expected = VecDeque::new();
+        for value in (split..DEQUE_START_SIZE).map(|i| i as i32 * 2) {
+            deque.push_back(value);
+            expected.push_back(value);
+        }
+        for value in (0..split).rev().map(|i| i as i32 * 2) {
+            deque.push_front(value);
+            expected.push_front(value);
+        }
+        for target in -1..=20 {
+            let result = deque.binary_search_by(|item| item.cmp(&target));
+            assert_eq!(result, expected.binary_search_by(|item| item.cmp(&target)));
+        }
+    }
+    assert_eq!(Deque::<i32>::new().binary_search_by(|item| item.cmp(&0)), Err(0));
+}
+
+#[test]
+fn test_zero_sized_type_contiguous() {
+    let mut deque = Deque::<()>::new();
+    deque.push_back(());
+    deque.push_front(());
+    deque.rotate_left(1);
+    assert_eq!(deque.make_contiguous().len(), 2);
+    assert_eq!(deque.as_slices(), (&[(), ()][..], &[][..]));
+}
+
+#[test]
+fn test_insert_remove() {
+    for offset in 0..DEQUE_START_SIZE {
+        for fill in [0, 1, 7, DEQUE_START_SIZE] {
+            for index in 0..=fill {
+                let (mut deque, mut expected) = create_rotated_deque(offset, fill);
+                assert!(deque.insert(-1, index).is_ok());
+                expected.insert(index, -1);
+                assert_same(&mut deque, &expected);
+                assert!(deque.insert(-2, fill - index / 2).is_ok());
+                expected.insert(fill - index / 2, -2);
+                assert_same(&mut deque, &expected);
+                for position in [index, 0, deque.length() - 1, deque.length() / 2] {
+                    assert_eq!(deque.remove(position).ok(), expected.remove(position));
+                    assert_same(&mut deque, &expected);
+                }
+                apply(&mut deque, &mut expected, Op::PushFront, 1);
+                apply(&mut deque, &mut expected, Op::PushBack, 2);
+                assert_same(&mut deque, &expected);
+            }
+        }
+    }
+    let mut deque = create_deque();
+    let len = DEQUE_ARRAY.len();
+    assert_eq!(deque.insert(0, len + 1), Err(Error::IndexOutOfBounds { index: len + 1, len }));
+    assert_eq!(deque.remove(len), Err(Error::IndexOutOfBounds { index: len, len }));
+}
+
+#[test]
+fn test_truncate_and_clear() {
+    for offset in 0..DEQUE_START_SIZE {
+        for length in [0, 3, 8, 9] {
+            let (mut deque, mut expected) = create_rotated_deque(offset, 8);
+            deque.truncate(length);
+            expected.truncate(length);
+            assert_same(&mut deque, &expected);
+            apply(&mut deque, &mut expected, Op::PushBack, 1);
+            assert_same(&mut deque, &expected);
+        }
+        let (mut deque, _) = create_rotated_deque(offset, 8);
+        deque.clear();
+        assert!(deque.is_empty());
+        assert_eq!(deque.buffer_size(), DEQUE_START_SIZE);
+        deque.push_back(1);
+        assert_eq!(deque.to_string(), "Head -> [1] <- Tail");
+    }
+}
+
+#[test]
+fn test_retain() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, mut expected) = create_rotated_deque(offset, DEQUE_START_SIZE);
+        deque.retain(|item| item % 3 != 0);
+        expected.retain(|item| item % 3 != 0);
+        assert_same(&mut deque, &expected);
+        deque.retain_mut(|item| {
+            *item *= 2;
+            *item % 4 == 0
+        });
+        expected.retain_mut(|item| {
+            *item *= 2;
+            *item % 4 == 0
+        });
+        assert_same(&mut deque, &expected);
+        apply(&mut deque, &mut expected, Op::PushBack, 1);
+        assert_same(&mut deque, &expected);
+        deque.retain(|_| false);
+        assert!(deque.is_empty());
+    }
+}
+
+#[test]
+fn test_retain_panic() {
+    let mut deque = Deque::from([1, 2, 3, 4, 5, 6]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        deque.retain(|&item| {
+            assert!(item != 4);
+            item % 2 == 1
+        })
+    }));
+    assert!(result.is_err());
+    // Visited elements are filtered, the others are kept
+    assert_eq!(deque.to_string(), "Head -> [1, 3, 4, 5, 6] <- Tail");
+    deque.push_back(7);
+    assert_eq!(deque.length(), 6);
+}
+
+#[test]
+fn test_append_and_split_off() {
+    for offset in 0..DEQUE_START_SIZE {
+        for at in [0, 3, 8] {
+            let (mut deque, mut expected) = create_rotated_deque(offset, 8);
+            let (mut other, mut other_expected) = create_rotated_deque(DEQUE_START_SIZE - offset, 7);
+            deque.append(&mut other);
+            expected.append(&mut other_expected);
+            assert_same(&mut deque, &expected);
+            assert_same(&mut other, &other_expected);
+            let mut split = deque.split_off(at);
+            let mut split_expected = expected.split_off(at);
+            assert_same(&mut deque, &expected);
+            assert_same(&mut split, &split_expected);
+            assert_eq!(split.buffer_size(), split_expected.len());
+            apply(&mut split, &mut split_expected, Op::PushFront, 1);
+            apply(&mut other, &mut other_expected, Op::PushBack, 2);
+            apply(&mut deque, &mut expected, Op::PushBack, 3);
+            assert_same(&mut split, &split_expected);
+            assert_same(&mut other, &other_expected);
+            assert_same(&mut deque, &expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "index 4 out of bounds (length is 3)")]
+fn test_split_off_out_of_bounds() {
+    Deque::from([1, 2, 3]).split_off(4);
+}
+
+#[test]
+fn test_resize_with() {
+    for offset in 0..DEQUE_START_SIZE {
+        let (mut deque, mut expected) = create_rotated_deque(offset, 8);
+        let mut next = 0;
+        deque.resize_with(15, || {
+            next += 1;
+            next
+        });
+        expected.extend(1..=7);
+        assert_same(&mut deque, &expected);
+        deque.resize_with(4, || unreachable!());
+        expected.truncate(4);
+        assert_same(&mut deque, &expected);
+    }
+}
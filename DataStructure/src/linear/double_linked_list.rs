@@ -1,3 +1,5 @@
+mod cursor;
+mod iter;
 mod node;
 
 #[cfg(test)]
@@ -5,37 +7,41 @@ mod tests;
 #[cfg(test)]
 mod benchs;
 
+use crate::error::{Error, Result};
 use node::Node;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem;
 use std::ptr::NonNull;
 use std::ops::{Drop};
 
-#[derive(Debug)]
+pub use cursor::{Cursor, CursorMut};
+pub use iter::{IntoIter, Iter, IterMut};
+
 pub struct DoubleLinkedList<T> {
     length: usize,
     head: Option<NonNull<Node<T>>>,
     tail: Option<NonNull<Node<T>>>,
+    // The list logically owns boxed nodes of T, for the drop check
+    _marker: PhantomData<Box<Node<T>>>,
 }
 
+// The list owns its nodes like a `Box<Node<T>>` chain would, only the links are raw pointers
+unsafe impl<T: Send> Send for DoubleLinkedList<T> {}
+unsafe impl<T: Sync> Sync for DoubleLinkedList<T> {}
+
 impl<T> DoubleLinkedList<T> {
     pub fn new() -> DoubleLinkedList<T> {
         DoubleLinkedList {
             length: 0,
             head: None, // First node of the list
             tail: None, // Last node of the list
+            _marker: PhantomData,
         }
     }
 
-    pub fn from_array(array: &[T]) -> DoubleLinkedList<T>
-    where
-        T: Clone,
-    {
-        let mut list: DoubleLinkedList<T> = DoubleLinkedList::new();
-        for val in array {
-            list.append(val.clone());
-        }
-        list
-    }
-
     pub fn length(&self) -> usize {
         self.length
     }
@@ -44,238 +50,510 @@ impl<T> DoubleLinkedList<T> {
         self.length == 0
     }
 
-    pub fn append(&mut self, value: T) {
-        // Creating a new node on heap memory
-        let mut new_node = Box::new(Node::new(value));
+    pub fn push_back(&mut self, value: T) {
+        // Creating a new node on heap memory, the list owns it through raw pointers
+        let new_node = NonNull::from(Box::leak(Box::new(Node::new(value))));
 
-        if self.length == 0 {
+        match self.tail {
             // List is empty - new node is head and tail at the same time
-            let new_node_ptr = Box::into_raw(new_node);
-            self.head = NonNull::new(new_node_ptr);
-            self.tail = NonNull::new(new_node_ptr);
-        } else {
+            None => self.head = Some(new_node),
             // List is not empty - Append to the old tail - The new node is the new tail
-            let mut old_tail;
-            unsafe {
-                old_tail = &mut *self.tail.as_mut().unwrap().as_mut();
-            }
-            new_node.predecessor = NonNull::new(old_tail);
-            let new_node_ptr = Box::into_raw(new_node);
-            old_tail.successor = NonNull::new(new_node_ptr);
-            self.tail = NonNull::new(new_node_ptr);
+            Some(old_tail) => unsafe {
+                (*new_node.as_ptr()).predecessor = Some(old_tail);
+                (*old_tail.as_ptr()).successor = Some(new_node);
+            },
         }
+        self.tail = Some(new_node);
 
         // Increase size of list by one
         self.length += 1;
     }
 
-    pub fn prepend(&mut self, value: T) {
-        // Creating a new node on heap memory
-        let mut new_node = Box::new(Node::new(value));
+    pub fn push_front(&mut self, value: T) {
+        // Creating a new node on heap memory, the list owns it through raw pointers
+        let new_node = NonNull::from(Box::leak(Box::new(Node::new(value))));
 
-        if self.length == 0 {
+        match self.head {
             // List is empty - new node is head and tail at the same time
-            let new_node_ptr = Box::into_raw(new_node);
-            self.head = NonNull::new(new_node_ptr);
-            self.tail = NonNull::new(new_node_ptr);
-        } else {
-            // List is not empty - Append to the old tail - The new node is the new tail
-            let mut old_head;
-            unsafe {
-                old_head = &mut *self.head.as_mut().unwrap().as_mut();
-            }
-            new_node.successor = NonNull::new(old_head);
-            let new_node_ptr = Box::into_raw(new_node);
-            old_head.predecessor = NonNull::new(new_node_ptr);
-            self.head = NonNull::new(new_node_ptr);
+            None => self.tail = Some(new_node),
+            // List is not empty - Prepend to the old head - The new node is the new head
+            Some(old_head) => unsafe {
+                (*new_node.as_ptr()).successor = Some(old_head);
+                (*old_head.as_ptr()).predecessor = Some(new_node);
+            },
         }
+        self.head = Some(new_node);
 
         // Increase size of list by one
         self.length += 1;
     }
 
-    pub fn insert(&mut self, value: T, index: usize) -> Result<(), String> {
-        // Creating a new node on heap memory
+    pub fn insert(&mut self, value: T, index: usize) -> Result<()> {
         if index > self.length {
-            return Err(format!("index {} out of bounds", index));
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
         }
         if index == 0 {
-            self.prepend(value);
+            self.push_front(value);
         } else if index == self.length {
-            self.append(value);
+            self.push_back(value);
         } else {
-            // List is not empty - Append to the old tail - The new node is the new tail
-            // Creating a new node on heap memory
-            let mut new_node = Box::new(Node::new(value));
-
-            // Iterate till node in the previous position where push the item
-            let mut previous_node = unsafe { self.head.as_mut().unwrap().as_mut() };
-            for _ in 0..index - 1 {
-                previous_node = unsafe { previous_node.successor.as_mut().unwrap().as_mut() };
+            // The new node goes between the nodes at positions index - 1 and index
+            let next_node = self.node_at(index);
+            unsafe {
+                let previous_node = (*next_node.as_ptr()).predecessor;
+                self.insert_between(previous_node, Some(next_node), value);
             }
-
-
-            new_node.successor = previous_node.successor;  // New node successor is now successor of predecessor (index - 1)
-            new_node.predecessor = NonNull::new(previous_node);  // New node successor is node in pos. index - 1
-            let new_node_ptr = Box::into_raw(new_node);
-            previous_node.successor = NonNull::new(new_node_ptr);  // The previous node successor is the new node
-            unsafe { (*new_node_ptr).successor.as_mut().unwrap().as_mut().predecessor = NonNull::new(new_node_ptr) };  // Predecessor of successor is the new node
-            // Increase size of list by one
-            self.length += 1;
         }
         Ok(())
     }
 
     pub fn tail_ref(&self) -> Option<&T> {
-        if let Some(tail) = &self.tail {
-            return Some(&unsafe { tail.as_ref() }.value);
-        }
-        None
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).value })
     }
 
     pub fn tail_mut(&mut self) -> Option<&mut T> {
-        if let Some(tail) = &mut self.tail {
-            return Some(&mut unsafe { tail.as_mut() }.value);
-        }
-        None
+        self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).value })
     }
 
     pub fn head_ref(&self) -> Option<&T> {
-        if let Some(head) = &self.head {
-            return Some(&unsafe { head.as_ref() }.value);
-        }
-        None
+        self.head.map(|head| unsafe { &(*head.as_ptr()).value })
     }
 
     pub fn head_mut(&mut self) -> Option<&mut T> {
-        if let Some(head) = &mut self.head {
-            return Some(&mut unsafe { head.as_mut() }.value);
-        }
-        None
+        self.head.map(|head| unsafe { &mut (*head.as_ptr()).value })
     }
 
-    pub fn pop_head(&mut self) -> Result<T, &'static str> {
-        if self.length == 0 {
-            return Err("Pop head on empty list");
-        }
-        let box_head = unsafe { Box::from_raw(self.head.unwrap().as_ptr()) };
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        let box_head = unsafe { Box::from_raw(head.as_ptr()) };
         self.head = box_head.successor;
-        if let Some(head) = &mut self.head {
-            unsafe { head.as_mut().predecessor = None };
-        } else {
+        match self.head {
+            Some(head) => unsafe { (*head.as_ptr()).predecessor = None },
             // Popped value was the unique one in the list, now it is empty
-            self.tail = None;
+            None => self.tail = None,
         }
         self.length -= 1;
-        Ok(box_head.value)
+        Some(box_head.value)
     }
 
-    pub fn pop_tail(&mut self) -> Result<T, &'static str> {
-        if self.length == 0 {
-            return Err("Pop tail on empty list");
-        }
-        let box_tail = unsafe { Box::from_raw(self.tail.unwrap().as_ptr()) };
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let box_tail = unsafe { Box::from_raw(tail.as_ptr()) };
         self.tail = box_tail.predecessor;
-        if let Some(tail) = &mut self.tail {
-            unsafe { tail.as_mut().successor = None };
-        } else {
+        match self.tail {
+            Some(tail) => unsafe { (*tail.as_ptr()).successor = None },
             // Popped value was the unique one in the list, now it is empty
-            self.head = None;
+            None => self.head = None,
         }
         self.length -= 1;
-        Ok(box_tail.value)
+        Some(box_tail.value)
     }
 
-    pub fn remove(&mut self, index: usize) -> Result<T, String> {
-        if self.length == 0 {
-            return Err(format!("Removing index {}: out of bound", index));
-        }
-        if index >= self.length {
-            return Err(format!("Index {} out of bounds (Length is {})", index, self.length));
-        }
-        if index == 0 {
-            return Ok(self.pop_head()?);
-        }
-        if index == self.length - 1 {
-            return Ok(self.pop_tail()?);
-        }
-        let mut previous_node = unsafe { self.head.as_mut().unwrap().as_mut() };
-        for _ in 0..index - 1 {
-            previous_node = unsafe { previous_node.successor.as_mut().unwrap().as_mut() };
-        }
+    /// [`pop_front`](Self::pop_front) failing with [`Error::Empty`] on an empty list
+    pub fn try_pop_front(&mut self) -> Result<T> {
+        self.pop_front().ok_or(Error::Empty)
+    }
 
-        let mut to_remove = unsafe {Box::from_raw(previous_node.successor.unwrap().as_ptr()) };
-        previous_node.successor = to_remove.successor;
-        unsafe { previous_node.successor.as_mut().unwrap().as_mut().predecessor = to_remove.predecessor };
+    /// [`pop_back`](Self::pop_back) failing with [`Error::Empty`] on an empty list
+    pub fn try_pop_back(&mut self) -> Result<T> {
+        self.pop_back().ok_or(Error::Empty)
+    }
 
-        self.length -= 1;
-        Ok(to_remove.value)
+    /// Same as [`try_pop_front`](Self::try_pop_front), the name the list had first
+    pub fn pop_head(&mut self) -> Result<T> {
+        self.try_pop_front()
     }
 
-    pub fn get_head_ref(&self) -> Result<&T, &'static str> {
-        if self.length == 0 {
-            return Err("Get head on empty list");
-        }
-        Ok(unsafe { &self.head.as_ref().unwrap().as_ref().value })
+    /// Same as [`try_pop_back`](Self::try_pop_back), the name the list had first
+    pub fn pop_tail(&mut self) -> Result<T> {
+        self.try_pop_back()
     }
 
-    pub fn get_head_mut(&mut self) -> Result<&mut T, &'static str> {
-        if self.length == 0 {
-            return Err("Get head on empty list");
+    pub fn remove(&mut self, index: usize) -> Result<T> {
+        if index >= self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
         }
-         Ok(unsafe { &mut self.head.as_mut().unwrap().as_mut().value })
+        Ok(unsafe { self.unlink_node(self.node_at(index)) }.value)
     }
 
-    pub fn get_tail_ref(&self) -> Result<&T, &'static str> {
-        if self.length == 0 {
-            return Err("Get tail on empty list");
-        }
-        Ok(unsafe { &self.tail.as_ref().unwrap().as_ref().value })
+    pub fn get_head_ref(&self) -> Result<&T> {
+        self.head_ref().ok_or(Error::Empty)
     }
 
-    pub fn get_tail_mut(&mut self) -> Result<&mut T, &'static str> {
-        if self.length == 0 {
-            return Err("Get tail on empty list");
-        }
-        Ok(unsafe { &mut self.tail.as_mut().unwrap().as_mut().value })
+    pub fn get_head_mut(&mut self) -> Result<&mut T> {
+        self.head_mut().ok_or(Error::Empty)
+    }
+
+    pub fn get_tail_ref(&self) -> Result<&T> {
+        self.tail_ref().ok_or(Error::Empty)
+    }
+
+    pub fn get_tail_mut(&mut self) -> Result<&mut T> {
+        self.tail_mut().ok_or(Error::Empty)
     }
 
-    pub fn get_ref(&mut self, index: usize) -> Result<&T, String> {
+    pub fn get_ref(&self, index: usize) -> Result<&T> {
         if index >= self.length {
-            return Err(format!("Index {} out of bounds (Length  {})", index, self.length));
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
         }
+        Ok(unsafe { &(*self.node_at(index).as_ptr()).value })
+    }
 
-        if index == 0 {
-            return Ok(self.get_head_ref()?);
-        }
-        if index == self.length - 1 {
-            return Ok(self.get_tail_ref()?);
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T> {
+        if index >= self.length {
+            return Err(Error::IndexOutOfBounds { index, len: self.length });
         }
+        Ok(unsafe { &mut (*self.node_at(index).as_ptr()).value })
+    }
+
+    /// Move every element of `other` after the tail, in the same order, leaving `other` empty.
+    /// Only the ends of the lists are relinked, in O(1).
+    ///
+    /// ```
+    /// use data_structure::linear::DoubleLinkedList;
+    ///
+    /// let mut list = DoubleLinkedList::from([1, 2]);
+    /// let mut other = DoubleLinkedList::from([3, 4]);
+    /// list.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(Vec::from(list), [1, 2, 3, 4]);
+    /// ```
+    pub fn append(&mut self, other: &mut DoubleLinkedList<T>) {
+        let other = mem::take(other);
+        unsafe { self.splice_between(self.tail, None, other) };
+    }
+
+    /// Split the list in two at position `at` from the head: the elements from `at` on are
+    /// moved to the returned list. Reaching position `at` walks from the closer end, then the
+    /// split itself is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> DoubleLinkedList<T> {
+        if at > self.length {
+            Error::IndexOutOfBounds { index: at, len: self.length }.raise();
+        }
+        let node = at.checked_sub(1).map(|index| self.node_at(index));
+        unsafe { self.split_after_node(node, at) }
+    }
+
+    /// Move every element of `other` to position `at` from the head, in the same order.
+    /// Reaching position `at` walks from the closer end, then the splice itself is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length.
+    ///
+    /// ```
+    /// use data_structure::linear::DoubleLinkedList;
+    ///
+    /// let mut list = DoubleLinkedList::from([1, 4]);
+    /// list.splice(1, DoubleLinkedList::from([2, 3]));
+    /// assert_eq!(Vec::from(list), [1, 2, 3, 4]);
+    /// ```
+    pub fn splice(&mut self, at: usize, other: DoubleLinkedList<T>) {
+        if at > self.length {
+            Error::IndexOutOfBounds { index: at, len: self.length }.raise();
+        }
+        let next = (at < self.length).then(|| self.node_at(at));
+        let previous = match next {
+            None => self.tail,
+            Some(next) => unsafe { (*next.as_ptr()).predecessor },
+        };
+        unsafe { self.splice_between(previous, next, other) };
+    }
+
+    /// Iterate over the elements from the head to the tail, or backwards with `rev`
+    ///
+    /// ```
+    /// use data_structure::linear::DoubleLinkedList;
+    ///
+    /// let list = DoubleLinkedList::from([1, 2, 3]);
+    /// assert!(list.iter().rev().eq(&[3, 2, 1]));
+    /// assert_eq!(list.iter().nth_back(2), Some(&1));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head, self.tail, self.length)
+    }
+
+    /// Iterate mutably over the elements from the head to the tail, or backwards with `rev`
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.head, self.tail, self.length)
+    }
+
+    /// Cursor on the head, or on the ghost position if the list is empty
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.head, 0)
+    }
+
+    /// Cursor on the tail, or on the ghost position if the list is empty
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.tail, self.length.saturating_sub(1))
+    }
+
+    /// Cursor on the head able to edit the list, or on the ghost position if the list is empty
+    ///
+    /// ```
+    /// use data_structure::linear::DoubleLinkedList;
+    ///
+    /// let mut list = DoubleLinkedList::from([1, 2, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// cursor.insert_after(3);
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// assert_eq!(cursor.current(), Some(&mut 3));
+    /// assert_eq!(Vec::from(list), [1, 3, 4]);
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let head = self.head;
+        CursorMut::new(self, head, 0)
+    }
 
-        let mut node = unsafe { self.head.as_ref().unwrap().as_ref() };
-        for _ in 0..index {
-            node = unsafe { node.successor.as_ref().unwrap().as_ref() };
+    /// Cursor on the tail able to edit the list, or on the ghost position if the list is empty
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let (tail, index) = (self.tail, self.length.saturating_sub(1));
+        CursorMut::new(self, tail, index)
+    }
+
+    /// Link a new node holding `value` between the adjacent nodes `previous` and `next`, `None`
+    /// standing for the ends of the list
+    ///
+    /// # Safety
+    ///
+    /// `previous` and `next` must be adjacent nodes of this list.
+    unsafe fn insert_between(
+        &mut self,
+        previous: Option<NonNull<Node<T>>>,
+        next: Option<NonNull<Node<T>>>,
+        value: T,
+    ) {
+        let new_node = NonNull::from(Box::leak(Box::new(Node::new(value))));
+        self.link_chain(previous, next, new_node, new_node, 1);
+    }
+
+    /// Move the nodes of `other` between the adjacent nodes `previous` and `next`, `None`
+    /// standing for the ends of the list
+    ///
+    /// # Safety
+    ///
+    /// `previous` and `next` must be adjacent nodes of this list.
+    unsafe fn splice_between(
+        &mut self,
+        previous: Option<NonNull<Node<T>>>,
+        next: Option<NonNull<Node<T>>>,
+        mut other: Self,
+    ) {
+        if let (Some(first), Some(last)) = (other.head.take(), other.tail.take()) {
+            self.link_chain(previous, next, first, last, mem::take(&mut other.length));
         }
-        Ok(&node.value)
     }
 
-    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, String> {
-        if index >= self.length {
-            return Err(format!("Index {} out of bounds (Length  {})", index, self.length));
+    /// Link the `count` nodes chained from `first` to `last` between the adjacent nodes
+    /// `previous` and `next`, `None` standing for the ends of the list
+    ///
+    /// # Safety
+    ///
+    /// The chain must not belong to any list, and `previous` and `next` must be adjacent nodes
+    /// of this one.
+    unsafe fn link_chain(
+        &mut self,
+        previous: Option<NonNull<Node<T>>>,
+        next: Option<NonNull<Node<T>>>,
+        first: NonNull<Node<T>>,
+        last: NonNull<Node<T>>,
+        count: usize,
+    ) {
+        (*first.as_ptr()).predecessor = previous;
+        (*last.as_ptr()).successor = next;
+        match previous {
+            None => self.head = Some(first),
+            Some(previous) => (*previous.as_ptr()).successor = Some(first),
+        }
+        match next {
+            None => self.tail = Some(last),
+            Some(next) => (*next.as_ptr()).predecessor = Some(last),
+        }
+        self.length += count;
+    }
+
+    /// Unlink `node` from the list, linking its neighbours together, and take it back as a box
+    ///
+    /// # Safety
+    ///
+    /// `node` must belong to this list.
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T>>) -> Box<Node<T>> {
+        let node = Box::from_raw(node.as_ptr());
+        match node.predecessor {
+            None => self.head = node.successor,
+            Some(previous) => (*previous.as_ptr()).successor = node.successor,
+        }
+        match node.successor {
+            None => self.tail = node.predecessor,
+            Some(next) => (*next.as_ptr()).predecessor = node.predecessor,
         }
+        self.length -= 1;
+        node
+    }
 
-        if index == 0 {
-            return Ok(self.get_head_mut()?);
+    /// Move the nodes after `node`, or all of them for `None`, to a new list
+    ///
+    /// # Safety
+    ///
+    /// `node` must belong to this list, at position `at - 1` (`at` is 0 for `None`).
+    unsafe fn split_after_node(&mut self, node: Option<NonNull<Node<T>>>, at: usize) -> Self {
+        let first = match node {
+            None => self.head.take(),
+            Some(node) => (*node.as_ptr()).successor.take(),
+        };
+        let Some(first) = first else {
+            return Self::new();
+        };
+        (*first.as_ptr()).predecessor = None;
+        let tail = mem::replace(&mut self.tail, node);
+        let length = mem::replace(&mut self.length, at) - at;
+        DoubleLinkedList { length, head: Some(first), tail, _marker: PhantomData }
+    }
+
+    /// Node at position `index` from the head, that must be lower than the length. The walk
+    /// starts from whichever end is closer.
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        debug_assert!(index < self.length);
+        unsafe {
+            if index < self.length / 2 {
+                let mut node = self.head.unwrap();
+                for _ in 0..index {
+                    node = (*node.as_ptr()).successor.unwrap();
+                }
+                node
+            } else {
+                let mut node = self.tail.unwrap();
+                for _ in index + 1..self.length {
+                    node = (*node.as_ptr()).predecessor.unwrap();
+                }
+                node
+            }
         }
-        if index == self.length - 1 {
-            return Ok(self.get_tail_mut()?);
+    }
+}
+
+impl<T: Debug> Debug for DoubleLinkedList<T> {
+    /// Elements from the head to the tail
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for DoubleLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for DoubleLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DoubleLinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for DoubleLinkedList<T> {
+    /// Lexicographic comparison of the elements from the head to the tail
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for DoubleLinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for DoubleLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+        for item in self.iter() {
+            item.hash(state);
         }
+    }
+}
+
+impl<T> FromIterator<T> for DoubleLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoubleLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
 
-        let mut node = unsafe { self.head.as_mut().unwrap().as_mut() };
-        for _ in 0..index {
-            node = unsafe { node.successor.as_mut().unwrap().as_mut() };
+impl<T> Extend<T> for DoubleLinkedList<T> {
+    /// Append the items after the tail, in order
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
         }
-        Ok(&mut node.value)
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for DoubleLinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T> From<Vec<T>> for DoubleLinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for DoubleLinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T> From<DoubleLinkedList<T>> for Vec<T> {
+    /// Elements go from the head to the tail
+    fn from(list: DoubleLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> IntoIterator for DoubleLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consume the list, yielding its elements from the head to the tail, or backwards with
+    /// `rev`
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoubleLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoubleLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> Default for DoubleLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -0,0 +1,248 @@
+//! Cursors over a [`DoubleLinkedList`], pointing at one element at a time and moving in both
+//! directions.
+//!
+//! Besides the elements, a cursor can rest on a "ghost" position between the tail and the head.
+//! Moving next from the tail or previous from the head reaches it, and moving on from there
+//! wraps around to the other end. On the ghost position, `current` returns `None`, and the
+//! edits before it apply at the tail and the edits after it at the head.
+
+use std::mem;
+use std::ptr::NonNull;
+
+use super::node::Node;
+use super::DoubleLinkedList;
+
+/// Read-only cursor returned by [`DoubleLinkedList::cursor_front`] and
+/// [`DoubleLinkedList::cursor_back`].
+pub struct Cursor<'a, T> {
+    list: &'a DoubleLinkedList<T>,
+    // Node the cursor points at, `None` for the ghost position, and its position from the head
+    // (the length of the list for the ghost position)
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+}
+
+/// Cursor able to edit the list in O(1) around its position, returned by
+/// [`DoubleLinkedList::cursor_front_mut`] and [`DoubleLinkedList::cursor_back_mut`].
+pub struct CursorMut<'a, T> {
+    list: &'a mut DoubleLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+}
+
+// Same as the references to the list the cursors stand for
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Cursor on node `current` of `list`, at position `index`
+    pub(super) fn new(
+        list: &'a DoubleLinkedList<T>,
+        current: Option<NonNull<Node<T>>>,
+        index: usize,
+    ) -> Self {
+        Cursor { list, current, index: current.map_or(list.length, |_| index) }
+    }
+
+    /// Position of the current element from the head, `None` on the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Move to the next element, from the ghost position to the head, and from the tail to the
+    /// ghost position
+    pub fn move_next(&mut self) {
+        (self.current, self.index) = step_next(self.list, self.current, self.index);
+    }
+
+    /// Move to the previous element, from the ghost position to the tail, and from the head to
+    /// the ghost position
+    pub fn move_prev(&mut self) {
+        (self.current, self.index) = step_prev(self.list, self.current, self.index);
+    }
+
+    /// Element the cursor points at, `None` on the ghost position
+    pub fn current(&self) -> Option<&'a T> {
+        // Nodes live as long as the list borrowed for 'a
+        self.current.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Element `move_next` would reach
+    pub fn peek_next(&self) -> Option<&'a T> {
+        next_node(self.list, self.current).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Element `move_prev` would reach
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        previous_node(self.list, self.current).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+}
+
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        Cursor { list: self.list, current: self.current, index: self.index }
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Cursor on node `current` of `list`, at position `index`
+    pub(super) fn new(
+        list: &'a mut DoubleLinkedList<T>,
+        current: Option<NonNull<Node<T>>>,
+        index: usize,
+    ) -> Self {
+        let index = current.map_or(list.length, |_| index);
+        CursorMut { list, current, index }
+    }
+
+    /// Position of the current element from the head, `None` on the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Move to the next element, from the ghost position to the head, and from the tail to the
+    /// ghost position
+    pub fn move_next(&mut self) {
+        (self.current, self.index) = step_next(self.list, self.current, self.index);
+    }
+
+    /// Move to the previous element, from the ghost position to the tail, and from the head to
+    /// the ghost position
+    pub fn move_prev(&mut self) {
+        (self.current, self.index) = step_prev(self.list, self.current, self.index);
+    }
+
+    /// Element the cursor points at, `None` on the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Element `move_next` would reach
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        next_node(self.list, self.current).map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Element `move_prev` would reach
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        previous_node(self.list, self.current).map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Read-only cursor at the same position, borrowing this one
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor { list: &*self.list, current: self.current, index: self.index }
+    }
+
+    /// Insert `value` before the current element, or at the tail on the ghost position. The
+    /// cursor stays on the same element.
+    pub fn insert_before(&mut self, value: T) {
+        let previous = previous_node(self.list, self.current);
+        unsafe { self.list.insert_between(previous, self.current, value) };
+        self.index += 1;
+    }
+
+    /// Insert `value` after the current element, or at the head on the ghost position. The
+    /// cursor stays on the same element.
+    pub fn insert_after(&mut self, value: T) {
+        let next = next_node(self.list, self.current);
+        unsafe { self.list.insert_between(self.current, next, value) };
+        if self.current.is_none() {
+            self.index += 1;
+        }
+    }
+
+    /// Remove the current element and move to the next one. Returns `None` and leaves the list
+    /// unchanged on the ghost position.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        self.current = unsafe { (*node.as_ptr()).successor };
+        Some(unsafe { self.list.unlink_node(node) }.value)
+    }
+
+    /// Move the elements before the current one to a new list, or all of them on the ghost
+    /// position. The current element becomes the head.
+    pub fn split_before(&mut self) -> DoubleLinkedList<T> {
+        let previous = previous_node(self.list, self.current);
+        // Split after the previous element, and keep the back part in the list
+        let back = unsafe { self.list.split_after_node(previous, self.index) };
+        self.index = 0;
+        mem::replace(self.list, back)
+    }
+
+    /// Move the elements after the current one to a new list, or all of them on the ghost
+    /// position. The current element becomes the tail.
+    pub fn split_after(&mut self) -> DoubleLinkedList<T> {
+        let at = self.current.map_or(0, |_| self.index + 1);
+        let back = unsafe { self.list.split_after_node(self.current, at) };
+        if self.current.is_none() {
+            self.index = 0;
+        }
+        back
+    }
+
+    /// Move the elements of `other` before the current one, or after the tail on the ghost
+    /// position
+    pub fn splice_before(&mut self, other: DoubleLinkedList<T>) {
+        let previous = previous_node(self.list, self.current);
+        self.index += other.length;
+        unsafe { self.list.splice_between(previous, self.current, other) };
+    }
+
+    /// Move the elements of `other` after the current one, or before the head on the ghost
+    /// position
+    pub fn splice_after(&mut self, other: DoubleLinkedList<T>) {
+        let next = next_node(self.list, self.current);
+        if self.current.is_none() {
+            self.index += other.length;
+        }
+        unsafe { self.list.splice_between(self.current, next, other) };
+    }
+}
+
+/// Node after `current` in `list`, the head after the ghost position
+fn next_node<T>(
+    list: &DoubleLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+) -> Option<NonNull<Node<T>>> {
+    match current {
+        None => list.head,
+        Some(node) => unsafe { (*node.as_ptr()).successor },
+    }
+}
+
+/// Node before `current` in `list`, the tail before the ghost position
+fn previous_node<T>(
+    list: &DoubleLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+) -> Option<NonNull<Node<T>>> {
+    match current {
+        None => list.tail,
+        Some(node) => unsafe { (*node.as_ptr()).predecessor },
+    }
+}
+
+/// Node after `current` at position `index`, with its own position
+fn step_next<T>(
+    list: &DoubleLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+) -> (Option<NonNull<Node<T>>>, usize) {
+    match current {
+        None => (list.head, 0),
+        Some(_) => (next_node(list, current), index + 1),
+    }
+}
+
+/// Node before `current` at position `index`, with its own position
+fn step_prev<T>(
+    list: &DoubleLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+) -> (Option<NonNull<Node<T>>>, usize) {
+    match current {
+        None => (list.tail, list.length.saturating_sub(1)),
+        Some(_) if index == 0 => (None, list.length),
+        Some(_) => (previous_node(list, current), index - 1),
+    }
+}
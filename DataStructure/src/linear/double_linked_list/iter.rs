@@ -0,0 +1,169 @@
+//! Iterators over a [`DoubleLinkedList`], yielding elements from the head to the tail, or from
+//! the tail to the head when reversed.
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use super::node::Node;
+use super::DoubleLinkedList;
+
+/// Borrowing iterator returned by [`DoubleLinkedList::iter`].
+pub struct Iter<'a, T> {
+    // Next nodes to yield from each end, and number of elements left between them
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    length: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+/// Mutably borrowing iterator returned by [`DoubleLinkedList::iter_mut`].
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    length: usize,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+/// Owning iterator returned by [`DoubleLinkedList::into_iter`].
+pub struct IntoIter<T> {
+    list: DoubleLinkedList<T>,
+}
+
+// Same as the references the iterators stand for
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<T> Iter<'_, T> {
+    /// Iterate over the `length` elements from node `head` to node `tail`
+    pub(super) fn new(
+        head: Option<NonNull<Node<T>>>,
+        tail: Option<NonNull<Node<T>>>,
+        length: usize,
+    ) -> Self {
+        Iter { head, tail, length, _marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        // Nodes live as long as the list borrowed for 'a
+        let node = unsafe { &*self.head?.as_ptr() };
+        self.head = node.successor;
+        self.length -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        let node = unsafe { &*self.tail?.as_ptr() };
+        self.tail = node.predecessor;
+        self.length -= 1;
+        Some(&node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { head: self.head, tail: self.tail, length: self.length, _marker: PhantomData }
+    }
+}
+
+impl<T> IterMut<'_, T> {
+    /// Iterate over the `length` elements from node `head` to node `tail`. The list is
+    /// borrowed mutably for `'a` by the caller.
+    pub(super) fn new(
+        head: Option<NonNull<Node<T>>>,
+        tail: Option<NonNull<Node<T>>>,
+        length: usize,
+    ) -> Self {
+        IterMut { head, tail, length, _marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        // Each node is yielded once, from either end, so the mutable references never alias.
+        // Only the value is borrowed: the links are still read through the other end.
+        let node = self.head?.as_ptr();
+        unsafe {
+            self.head = (*node).successor;
+            self.length -= 1;
+            Some(&mut (*node).value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        let node = self.tail?.as_ptr();
+        unsafe {
+            self.tail = (*node).predecessor;
+            self.length -= 1;
+            Some(&mut (*node).value)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> IntoIter<T> {
+    pub(super) fn new(list: DoubleLinkedList<T>) -> Self {
+        IntoIter { list }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.length, Some(self.list.length))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
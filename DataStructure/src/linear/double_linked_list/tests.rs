@@ -1,9 +1,11 @@
 use super::DoubleLinkedList;
+use crate::error::{Error, Result};
+use std::collections::HashSet;
 
 const FROM_ARRAY: [i32; 11] = [-5, 0, 3, 15, 16, 18, -20, -15, -3, -8, 5];
 
 fn create_list() -> DoubleLinkedList<i32> {
-    DoubleLinkedList::from_array(&FROM_ARRAY)
+    DoubleLinkedList::from(FROM_ARRAY)
 }
 fn create_empty_list() -> DoubleLinkedList<i32> {
     DoubleLinkedList::new()
@@ -13,34 +15,48 @@ fn create_empty_list() -> DoubleLinkedList<i32> {
 fn test_new() {
     let list = create_empty_list();
     assert_eq!(list.length(), 0);
-    assert_eq!(list.is_empty(), true);
+    assert!(list.is_empty());
 }
 
 #[test]
-fn test_from_array() {
+fn test_from() {
     let list = create_list();
     assert_eq!(list.length(), 11);
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
     assert_eq!(*list.head_ref().unwrap(), -5);
     assert_eq!(*list.tail_ref().unwrap(), 5);
+    assert_eq!(Vec::from(list), FROM_ARRAY);
+    assert_eq!(Vec::from(DoubleLinkedList::from(FROM_ARRAY.to_vec())), FROM_ARRAY);
+    assert_eq!(Vec::from(create_empty_list()), []);
 }
 
 #[test]
-fn test_append() {
+fn test_from_iter_and_extend() {
+    let mut list: DoubleLinkedList<i32> = FROM_ARRAY.into_iter().collect();
+    assert_eq!(list.length(), FROM_ARRAY.len());
+    list.extend([1, 2]);
+    list.extend(&[3]);
+    assert_eq!(*list.tail_ref().unwrap(), 3);
+    let expected: Vec<i32> = FROM_ARRAY.into_iter().chain([1, 2, 3]).collect();
+    assert_eq!(Vec::from(list), expected);
+}
+
+#[test]
+fn test_push_back() {
     let mut list: DoubleLinkedList<i32> = create_empty_list();
-    list.append(3);
+    list.push_back(3);
     assert_eq!(*list.tail_ref().unwrap(), 3);
-    list.append(4);
+    list.push_back(4);
     assert_eq!(*list.tail_ref().unwrap(), 4);
     assert_eq!(*list.head_ref().unwrap(), 3);
 }
 
 #[test]
-fn test_prepend() {
+fn test_push_front() {
     let mut list: DoubleLinkedList<i32> = create_empty_list();
-    list.prepend(3);
+    list.push_front(3);
     assert_eq!(*list.head_ref().unwrap(), 3);
-    list.prepend(4);
+    list.push_front(4);
     assert_eq!(*list.head_ref().unwrap(), 4);
     assert_eq!(*list.tail_ref().unwrap(), 3);
 }
@@ -53,6 +69,10 @@ fn test_pop() {
     assert_eq!(list.pop_head().unwrap(), -5);
     assert_eq!(list.pop_head().unwrap(), 0);
     assert_eq!(list.length(), FROM_ARRAY.len() - 4usize);
+    assert_eq!(list.pop_back(), Some(-3));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.try_pop_back(), Ok(-15));
+    assert_eq!(list.try_pop_front(), Ok(15));
 }
 
 #[test]
@@ -64,7 +84,7 @@ fn test_get() {
     assert_eq!(*list.get_head_ref().unwrap(), -5);
     assert_eq!(*list.get_ref(5).unwrap(), FROM_ARRAY[5]);
     assert_eq!(*list.get_ref(5).unwrap(), FROM_ARRAY[5]);
-    let mut val = list.get_mut(5).unwrap();
+    let val = list.get_mut(5).unwrap();
     assert_eq!(*val, FROM_ARRAY[5]);
     *val += 5;
     assert_eq!(*list.get_mut(5).unwrap(), FROM_ARRAY[5] + 5);
@@ -78,13 +98,13 @@ fn test_get_edge_cases() {
     let last_index = FROM_ARRAY.len() - 1;
     assert_eq!(*list.get_ref(0).unwrap(), FROM_ARRAY[0]);
     assert_eq!(*list.get_ref(last_index).unwrap(), FROM_ARRAY[last_index]);
-    let mut h = list.get_mut(0).unwrap();
+    let h = list.get_mut(0).unwrap();
     assert_eq!(*h, FROM_ARRAY[0]);
     *h += 5;
     assert_eq!(*list.get_mut(0).unwrap(), FROM_ARRAY[0] + 5);
     assert_eq!(*list.get_ref(0).unwrap(), FROM_ARRAY[0] + 5);
 
-    let mut t = list.get_mut(last_index).unwrap();
+    let t = list.get_mut(last_index).unwrap();
     assert_eq!(*t, FROM_ARRAY[last_index]);
     *t += 44;
     assert_eq!(*list.get_mut(last_index).unwrap(), FROM_ARRAY[last_index] + 44);
@@ -120,7 +140,7 @@ fn test_remove() {
 #[test]
 fn test_remove_empty() {
     let mut list = create_empty_list();
-    let mut removed = list.remove(3);
+    let removed = list.remove(3);
     assert!(removed.is_err());
 }
 
@@ -155,7 +175,7 @@ fn test_insert() {
 #[test]
 fn test_head() {
     let mut list = create_list();
-    let mut val = list.head_mut().unwrap();
+    let val = list.head_mut().unwrap();
     assert_eq!(*val, -5);
     *val = -100;
     assert_eq!(*list.head_mut().unwrap(), -100);
@@ -167,7 +187,7 @@ fn test_head() {
 #[test]
 fn test_tail() {
     let mut list = create_list();
-    let mut val = list.tail_mut().unwrap();
+    let val = list.tail_mut().unwrap();
     assert_eq!(*val, 5);
     *val = 100;
     assert_eq!(*list.tail_mut().unwrap(), 100);
@@ -175,3 +195,334 @@ fn test_tail() {
     assert!(list.tail_mut().is_none());
     assert!(list.tail_ref().is_none());
 }
+
+#[test]
+fn test_errors() {
+    let mut list = create_list();
+    let len = FROM_ARRAY.len();
+    assert_eq!(list.get_ref(len), Err(Error::IndexOutOfBounds { index: len, len }));
+    assert_eq!(list.insert(0, len + 1), Err(Error::IndexOutOfBounds { index: len + 1, len }));
+    assert_eq!(list.remove(len), Err(Error::IndexOutOfBounds { index: len, len }));
+    list = create_empty_list();
+    assert_eq!(list.pop_head(), Err(Error::Empty));
+    assert_eq!(list.pop_tail(), Err(Error::Empty));
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(list.try_pop_front(), Err(Error::Empty));
+    assert_eq!(list.try_pop_back(), Err(Error::Empty));
+    assert_eq!(list.get_head_ref(), Err(Error::Empty));
+    assert_eq!(list.get_tail_mut(), Err(Error::Empty));
+    assert_eq!(list.remove(0), Err(Error::IndexOutOfBounds { index: 0, len: 0 }));
+}
+
+#[test]
+fn test_error_propagation() {
+    fn sum_ends(list: &DoubleLinkedList<i32>) -> Result<i32> {
+        Ok(*list.get_head_ref()? + *list.get_tail_ref()?)
+    }
+    assert_eq!(sum_ends(&create_list()), Ok(FROM_ARRAY[0] + FROM_ARRAY[FROM_ARRAY.len() - 1]));
+    assert_eq!(sum_ends(&create_empty_list()), Err(Error::Empty));
+}
+
+#[test]
+fn test_standard_traits() {
+    let list = create_list();
+    assert_eq!(format!("{:?}", list), format!("{:?}", FROM_ARRAY));
+    let mut clone = list.clone();
+    assert_eq!(clone, list);
+    clone.push_front(-5);
+    assert!(clone < list);
+    assert_eq!(clone.pop_head(), Ok(-5));
+    clone.push_back(0);
+    assert!(clone > list);
+    assert_eq!(DoubleLinkedList::<i32>::default(), create_empty_list());
+    let set: HashSet<DoubleLinkedList<i32>> = [list.clone(), create_list(), clone].into();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&list));
+    let handle = std::thread::spawn(move || list.length());
+    assert_eq!(handle.join().unwrap(), FROM_ARRAY.len());
+}
+
+#[test]
+fn test_iter() {
+    let list = create_list();
+    assert!(list.iter().eq(FROM_ARRAY.iter()));
+    assert!(list.iter().rev().eq(FROM_ARRAY.iter().rev()));
+    assert_eq!(list.iter().len(), FROM_ARRAY.len());
+    assert_eq!(list.iter().nth_back(2), Some(&FROM_ARRAY[8]));
+    assert_eq!(list.iter().nth(11), None);
+
+    // Both ends meet in the middle without yielding a node twice
+    let mut iter = list.iter();
+    assert_eq!(iter.next(), Some(&-5));
+    assert_eq!(iter.next_back(), Some(&5));
+    assert_eq!(iter.len(), 9);
+    let middle: Vec<i32> = iter.clone().copied().collect();
+    assert_eq!(middle, FROM_ARRAY[1..10]);
+    assert_eq!(iter.by_ref().rev().count(), 9);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let pairs: Vec<(&i32, &i32)> = list.iter().zip(list.iter().rev()).take(2).collect();
+    assert_eq!(pairs, [(&-5, &5), (&0, &-8)]);
+    assert_eq!(create_empty_list().iter().next_back(), None);
+    assert_eq!((&list).into_iter().sum::<i32>(), FROM_ARRAY.iter().sum());
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut list = create_list();
+    let mut iter = list.iter_mut();
+    let (first, last) = (iter.next().unwrap(), iter.next_back().unwrap());
+    std::mem::swap(first, last);
+    for item in iter.rev() {
+        *item *= 2;
+    }
+    let expected: Vec<i32> = [5]
+        .into_iter()
+        .chain(FROM_ARRAY[1..10].iter().map(|item| item * 2))
+        .chain([-5])
+        .collect();
+    assert!(list.iter().eq(expected.iter()));
+    for item in &mut list {
+        *item += 1;
+    }
+    assert!(list.iter().zip(&expected).all(|(item, old)| *item == old + 1));
+    // The links are still sound after the values have been borrowed
+    assert!(list.insert(1, 5).is_ok());
+    assert_eq!(list.get_ref(5), Ok(&1));
+    assert_eq!(list.remove(5), Ok(1));
+    assert_eq!(list.remove(5), Ok(expected[5] + 1));
+    assert_eq!(list.length(), FROM_ARRAY.len() - 1);
+}
+
+#[test]
+fn test_into_iter() {
+    let mut iter = create_list().into_iter();
+    assert_eq!(iter.len(), FROM_ARRAY.len());
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.next(), Some(-5));
+    assert_eq!(iter.nth_back(1), Some(-3));
+    let rest: Vec<i32> = iter.rev().collect();
+    let expected: Vec<i32> = FROM_ARRAY[1..8].iter().rev().copied().collect();
+    assert_eq!(rest, expected);
+
+    let mut list = create_empty_list();
+    for item in create_list().into_iter().rev() {
+        list.push_back(item);
+    }
+    assert!(list.into_iter().eq(FROM_ARRAY.into_iter().rev()));
+    // Elements left in the iterator are dropped with it
+    let strings = DoubleLinkedList::from([String::from("a"), String::from("b")]);
+    assert_eq!(strings.into_iter().next_back().as_deref(), Some("b"));
+}
+
+/// Elements of `list` read from both ends, checking the predecessor links match
+fn checked_vec(list: &DoubleLinkedList<i32>) -> Vec<i32> {
+    let forward: Vec<i32> = list.iter().copied().collect();
+    let mut backward: Vec<i32> = list.iter().rev().copied().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(forward.len(), list.length());
+    assert_eq!(list.head_ref(), forward.first());
+    assert_eq!(list.tail_ref(), forward.last());
+    forward
+}
+
+#[test]
+fn test_cursor() {
+    let list = create_list();
+    let mut cursor = list.cursor_front();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&-5)));
+    assert_eq!(cursor.peek_prev(), None);
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    assert_eq!(cursor.peek_next(), Some(&-5));
+    assert_eq!(cursor.peek_prev(), Some(&5));
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(10), Some(&5)));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&-5)));
+
+    let mut cursor = list.cursor_back();
+    let mut values = Vec::new();
+    while let Some(value) = cursor.current() {
+        assert_eq!(cursor.index(), Some(FROM_ARRAY.len() - 1 - values.len()));
+        values.push(*value);
+        cursor.move_prev();
+    }
+    assert!(values.into_iter().eq(FROM_ARRAY.into_iter().rev()));
+
+    let empty = create_empty_list();
+    let mut cursor = empty.cursor_back();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    cursor.move_next();
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.peek_next(), cursor.peek_prev()), (None, None, None));
+}
+
+#[test]
+fn test_cursor_mut_insert_remove() {
+    let mut list = DoubleLinkedList::from([1, 2, 3]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.insert_before(10);
+    cursor.insert_after(20);
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 2)));
+    *cursor.peek_next().unwrap() += 1;
+    assert_eq!(cursor.remove_current(), Some(2));
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 21)));
+    assert_eq!(cursor.as_cursor().peek_prev(), Some(&10));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), None);
+    // On the ghost position, inserting before pushes at the tail and after at the head
+    cursor.insert_before(4);
+    cursor.insert_after(0);
+    assert_eq!(cursor.index(), None);
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 0)));
+    assert_eq!(checked_vec(&list), [0, 1, 10, 21, 3, 4]);
+
+    // Removing the tail leaves the cursor on the ghost position
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.remove_current(), Some(4));
+    assert_eq!(cursor.current(), None);
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&mut 3)));
+    assert_eq!(cursor.remove_current(), Some(3));
+    cursor.move_prev();
+    assert_eq!(cursor.remove_current(), Some(21));
+    assert_eq!(checked_vec(&list), [0, 1, 10]);
+    let mut cursor = list.cursor_front_mut();
+    while cursor.remove_current().is_some() {}
+    assert!(list.is_empty());
+    list.cursor_back_mut().insert_after(7);
+    assert_eq!(checked_vec(&list), [7]);
+}
+
+#[test]
+fn test_cursor_mut_split() {
+    let mut list = create_list();
+    let mut cursor = list.cursor_front_mut();
+    for _ in 0..3 {
+        cursor.move_next();
+    }
+    let front = cursor.split_before();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 15)));
+    let back = cursor.split_after();
+    assert_eq!((cursor.index(), cursor.peek_next()), (Some(0), None));
+    assert_eq!(checked_vec(&front), FROM_ARRAY[..3]);
+    assert_eq!(checked_vec(&list), [15]);
+    assert_eq!(checked_vec(&back), FROM_ARRAY[4..]);
+
+    // On the ghost position, the whole list is split off
+    let mut list = create_list();
+    let mut cursor = list.cursor_back_mut();
+    cursor.move_next();
+    assert_eq!(checked_vec(&cursor.split_before()), FROM_ARRAY);
+    assert_eq!(cursor.split_after(), create_empty_list());
+    let mut list = create_list();
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_prev();
+    assert_eq!(checked_vec(&cursor.split_after()), FROM_ARRAY);
+    assert_eq!(cursor.index(), None);
+    assert!(list.is_empty());
+
+    // Splitting at the ends leaves an empty side
+    let mut list = create_list();
+    let mut cursor = list.cursor_back_mut();
+    assert!(cursor.split_after().is_empty());
+    assert_eq!(cursor.index(), Some(10));
+    cursor.move_next();
+    cursor.move_next();
+    assert!(cursor.split_before().is_empty());
+    assert_eq!(checked_vec(&list), FROM_ARRAY);
+}
+
+#[test]
+fn test_cursor_mut_splice() {
+    let mut list = DoubleLinkedList::from([1, 5]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.splice_after(DoubleLinkedList::from([2, 3]));
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.splice_before(DoubleLinkedList::from([4]));
+    assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&mut 5)));
+    cursor.splice_before(create_empty_list());
+    cursor.splice_after(create_empty_list());
+    assert_eq!(cursor.index(), Some(4));
+    // On the ghost position, splicing after goes at the head and before at the tail
+    cursor.move_next();
+    cursor.splice_after(DoubleLinkedList::from([-1, 0]));
+    cursor.splice_before(DoubleLinkedList::from([6]));
+    assert_eq!(cursor.index(), None);
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(7), Some(&mut 6)));
+    assert_eq!(checked_vec(&list), [-1, 0, 1, 2, 3, 4, 5, 6]);
+
+    let mut empty = create_empty_list();
+    empty.cursor_front_mut().splice_before(DoubleLinkedList::from([1, 2]));
+    assert_eq!(checked_vec(&empty), [1, 2]);
+}
+
+#[test]
+fn test_append() {
+    let mut list = create_list();
+    let mut other = DoubleLinkedList::from([1, 2]);
+    list.append(&mut other);
+    assert!(other.is_empty());
+    let expected: Vec<i32> = FROM_ARRAY.into_iter().chain([1, 2]).collect();
+    assert_eq!(checked_vec(&list), expected);
+    list.append(&mut other);
+    assert_eq!(list.length(), FROM_ARRAY.len() + 2);
+    other.append(&mut list);
+    assert!(list.is_empty());
+    assert_eq!(checked_vec(&other), expected);
+    // The appended lists stay usable
+    other.push_back(3);
+    list.push_front(0);
+    assert_eq!(checked_vec(&list), [0]);
+}
+
+#[test]
+fn test_split_off() {
+    let mut list = create_list();
+    let back = list.split_off(8);
+    assert_eq!(checked_vec(&list), FROM_ARRAY[..8]);
+    assert_eq!(checked_vec(&back), FROM_ARRAY[8..]);
+    let mut front = list.split_off(0);
+    assert!(list.is_empty());
+    assert_eq!(checked_vec(&front.split_off(2)), FROM_ARRAY[2..8]);
+    assert!(front.split_off(2).is_empty());
+    assert_eq!(checked_vec(&front), FROM_ARRAY[..2]);
+    assert!(list.split_off(0).is_empty());
+}
+
+#[test]
+fn test_splice() {
+    let mut list = DoubleLinkedList::from([2, 5]);
+    list.splice(1, DoubleLinkedList::from([3, 4]));
+    list.splice(0, DoubleLinkedList::from([0, 1]));
+    list.splice(6, DoubleLinkedList::from([6]));
+    list.splice(3, create_empty_list());
+    assert_eq!(checked_vec(&list), [0, 1, 2, 3, 4, 5, 6]);
+    let mut empty = create_empty_list();
+    empty.splice(0, list);
+    assert_eq!(empty.length(), 7);
+}
+
+#[test]
+#[should_panic(expected = "index 12 out of bounds (length is 11)")]
+fn test_split_off_out_of_bounds() {
+    create_list().split_off(12);
+}
+
+#[test]
+#[should_panic(expected = "index 3 out of bounds (length is 2)")]
+fn test_splice_out_of_bounds() {
+    DoubleLinkedList::from([1, 2]).splice(3, create_list());
+}
@@ -0,0 +1,326 @@
+//! Drop accounting of the buffer-backed collections. Payloads own heap memory and count their
+//! drops, so that leaks show up as missing drops and double drops as extra drops (or as double
+//! frees when run under Miri: `cargo +nightly miri test`).
+
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use super::{ArrayDeque, ArrayStack, Deque, Queue, SmallStack, Stack};
+
+/// Payload owning heap memory and counting its drops in `drops`
+pub(super) struct DropCounter {
+    pub(super) value: String,
+    drops: Rc<Cell<usize>>,
+}
+
+impl DropCounter {
+    pub(super) fn new(value: usize, drops: &Rc<Cell<usize>>) -> DropCounter {
+        DropCounter { value: value.to_string(), drops: Rc::clone(drops) }
+    }
+}
+
+impl Clone for DropCounter {
+    fn clone(&self) -> Self {
+        DropCounter { value: self.value.clone(), drops: Rc::clone(&self.drops) }
+    }
+}
+
+impl Display for DropCounter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+thread_local! {
+    static ZST_DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Zero-sized payload counting its drops in a thread local counter
+struct ZstDropCounter;
+
+impl Drop for ZstDropCounter {
+    fn drop(&mut self) {
+        ZST_DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+}
+
+// Larger than the starting buffer of every collection, so that each one grows at least once
+const ITEMS: usize = 25;
+
+#[test]
+fn test_stack_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = Stack::new();
+    for i in 0..ITEMS {
+        stack.push(DropCounter::new(i, &drops));
+    }
+    assert_eq!(drops.get(), 0);
+    for i in (ITEMS - 5..ITEMS).rev() {
+        assert_eq!(stack.pop().unwrap().value, i.to_string());
+    }
+    assert_eq!(drops.get(), 5);
+    *stack.top_mut().unwrap() = DropCounter::new(100, &drops);
+    assert_eq!(drops.get(), 6);
+    let _ = format!("{}", stack);
+    assert_eq!(drops.get(), 6);
+    drop(stack);
+    assert_eq!(drops.get(), ITEMS + 1);
+}
+
+#[test]
+fn test_queue_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut queue = Queue::new();
+    // Move the head forward, then wrap around the end of the buffer and grow it
+    for i in 0..8 {
+        queue.push(DropCounter::new(i, &drops));
+    }
+    for i in 0..5 {
+        assert_eq!(queue.pop().unwrap().value, i.to_string());
+    }
+    for i in 8..ITEMS {
+        queue.push(DropCounter::new(i, &drops));
+    }
+    assert_eq!(drops.get(), 5);
+    *queue.head_mut().unwrap() = DropCounter::new(100, &drops);
+    assert_eq!(drops.get(), 6);
+    let _ = format!("{}", queue);
+    assert_eq!(drops.get(), 6);
+    drop(queue);
+    assert_eq!(drops.get(), ITEMS + 1);
+}
+
+#[test]
+fn test_deque_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut deque = Deque::new();
+    for i in 0..ITEMS {
+        if i % 2 == 0 {
+            deque.push_front(DropCounter::new(i, &drops));
+        } else {
+            deque.push_back(DropCounter::new(i, &drops));
+        }
+    }
+    assert_eq!(deque.pop_front().unwrap().value, (ITEMS - 1).to_string());
+    assert_eq!(deque.pop_back().unwrap().value, (ITEMS - 2).to_string());
+    assert_eq!(drops.get(), 2);
+    *deque.head_mut().unwrap() = DropCounter::new(100, &drops);
+    *deque.tail_mut().unwrap() = DropCounter::new(101, &drops);
+    assert_eq!(drops.get(), 4);
+    let _ = format!("{}", deque);
+    assert_eq!(drops.get(), 4);
+    drop(deque);
+    assert_eq!(drops.get(), ITEMS + 2);
+}
+
+#[test]
+fn test_deque_bulk_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut deque = Deque::new();
+    for i in 0..ITEMS {
+        if i % 2 == 0 {
+            deque.push_front(DropCounter::new(i, &drops));
+        } else {
+            deque.push_back(DropCounter::new(i, &drops));
+        }
+    }
+    assert!(deque.insert(DropCounter::new(100, &drops), 3).is_ok());
+    drop(deque.remove(10));
+    assert_eq!(drops.get(), 1);
+    deque.truncate(20);
+    assert_eq!(drops.get(), 6);
+    let removed = deque.iter().filter(|item| item.value.len() != 1).count();
+    deque.retain(|item| item.value.len() == 1);
+    assert_eq!(drops.get(), 6 + removed);
+    let mut other: Deque<DropCounter> = (0..5).map(|i| DropCounter::new(i, &drops)).collect();
+    deque.append(&mut other);
+    drop(other);
+    assert_eq!(drops.get(), 6 + removed);
+    drop(deque.split_off(2));
+    deque.clear();
+    assert_eq!(drops.get(), ITEMS + 6);
+    drop(deque);
+    assert_eq!(drops.get(), ITEMS + 6);
+}
+
+#[test]
+fn test_clone_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = Stack::new();
+    let mut queue = Queue::new();
+    let mut deque = Deque::new();
+    for i in 0..ITEMS {
+        stack.push(DropCounter::new(i, &drops));
+        queue.push(DropCounter::new(i, &drops));
+        deque.push_front(DropCounter::new(i, &drops));
+    }
+    let clones = (stack.clone(), queue.clone(), deque.clone());
+    drop((stack, queue, deque));
+    assert_eq!(drops.get(), 3 * ITEMS);
+    drop(clones);
+    assert_eq!(drops.get(), 6 * ITEMS);
+}
+
+#[test]
+fn test_zero_sized_type_drops() {
+    let mut stack = Stack::new();
+    let mut queue = Queue::new();
+    let mut deque = Deque::new();
+    for _ in 0..ITEMS {
+        stack.push(ZstDropCounter);
+        queue.push(ZstDropCounter);
+        deque.push_front(ZstDropCounter);
+    }
+    drop((stack.pop(), queue.pop(), deque.pop_back()));
+    assert_eq!(ZST_DROPS.with(Cell::get), 3);
+    drop((stack, queue, deque));
+    assert_eq!(ZST_DROPS.with(Cell::get), 3 * ITEMS);
+}
+
+#[test]
+fn test_array_collections_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = ArrayStack::<DropCounter, ITEMS>::new();
+    let mut deque = ArrayDeque::<DropCounter, ITEMS>::new();
+    // Wrap the deque around the end of its array
+    for i in 0..ITEMS {
+        stack.push(DropCounter::new(i, &drops)).ok().unwrap();
+        if i % 2 == 0 {
+            deque.push_front(DropCounter::new(i, &drops)).ok().unwrap();
+        } else {
+            deque.push_back(DropCounter::new(i, &drops)).ok().unwrap();
+        }
+    }
+    // Rejected items are handed back, not dropped
+    let rejected = stack.push(DropCounter::new(100, &drops)).unwrap_err();
+    assert_eq!(drops.get(), 0);
+    drop(rejected);
+    assert_eq!(drops.get(), 1);
+    drop((stack.pop(), deque.pop_front(), deque.pop_back()));
+    assert_eq!(drops.get(), 4);
+    *deque.head_mut().unwrap() = DropCounter::new(101, &drops);
+    assert_eq!(drops.get(), 5);
+    let clones = (stack.clone(), deque.clone());
+    drop((stack, deque));
+    assert_eq!(drops.get(), 5 + 2 * ITEMS - 3);
+    drop(clones);
+    assert_eq!(drops.get(), 5 + 4 * ITEMS - 6);
+}
+
+#[test]
+fn test_small_stack_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = SmallStack::<DropCounter, 8>::new();
+    // Spill, move back inline, then spill again
+    for i in 0..ITEMS {
+        stack.push(DropCounter::new(i, &drops));
+    }
+    for _ in 0..ITEMS - 4 {
+        stack.pop();
+    }
+    assert_eq!(drops.get(), ITEMS - 4);
+    stack.shrink_to_fit();
+    assert!(!stack.spilled());
+    let clone = stack.clone();
+    for i in 4..ITEMS {
+        stack.push(DropCounter::new(i, &drops));
+    }
+    assert!(stack.spilled());
+    assert_eq!(drops.get(), ITEMS - 4);
+    drop(stack);
+    assert_eq!(drops.get(), 2 * ITEMS - 4);
+    drop(clone);
+    assert_eq!(drops.get(), 2 * ITEMS);
+}
+
+#[test]
+fn test_iterator_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = Stack::new();
+    let mut queue = Queue::new();
+    let mut deque = Deque::new();
+    // Wrap the queue and the deque around the end of their buffer
+    for i in 0..8 {
+        queue.push(DropCounter::new(i, &drops));
+        drop(queue.pop());
+        deque.push_front(DropCounter::new(i, &drops));
+    }
+    assert_eq!(drops.get(), 8);
+    for i in 0..ITEMS {
+        stack.push(DropCounter::new(i, &drops));
+        queue.push(DropCounter::new(i, &drops));
+        deque.push_back(DropCounter::new(i, &drops));
+    }
+    let mut stack_iter = stack.into_iter();
+    let mut queue_iter = queue.into_iter();
+    drop((stack_iter.next(), queue_iter.next_back()));
+    assert_eq!(drops.get(), 10);
+    // Elements not yielded are dropped with the iterators
+    drop((stack_iter, queue_iter));
+    assert_eq!(drops.get(), 8 + 2 * ITEMS);
+
+    let mut drain = deque.drain(4..ITEMS);
+    drop((drain.next(), drain.next_back()));
+    assert_eq!(drops.get(), 10 + 2 * ITEMS);
+    drop(drain);
+    assert_eq!(drops.get(), 4 + 3 * ITEMS);
+    assert_eq!(deque.length(), 12);
+    drop(deque);
+    assert_eq!(drops.get(), 16 + 3 * ITEMS);
+}
+
+#[test]
+fn test_small_stack_iterator_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut inline = SmallStack::<DropCounter, 8>::new();
+    let mut spilled = SmallStack::<DropCounter, 8>::new();
+    for i in 0..6 {
+        inline.push(DropCounter::new(i, &drops));
+    }
+    for i in 0..ITEMS {
+        spilled.push(DropCounter::new(i, &drops));
+    }
+    // Inline or spilled, drains close the gap and owning iterators drop what is left
+    let mut drain = inline.drain(1..4);
+    assert_eq!(drain.next().unwrap().value, "4");
+    drop(drain);
+    assert_eq!(drops.get(), 3);
+    assert_eq!(inline.length(), 3);
+    drop(spilled.drain(..ITEMS - 6));
+    assert_eq!(drops.get(), ITEMS - 3);
+    assert!(spilled.spilled());
+    for stack in [inline, spilled] {
+        let mut iter = stack.into_iter();
+        assert_eq!(iter.next_back().unwrap().value, "0");
+        assert_eq!(iter.next().unwrap().value, "5");
+        drop(iter);
+    }
+    assert_eq!(drops.get(), ITEMS + 6);
+}
+
+#[test]
+// Leaks on purpose, which Miri reports as an error
+#[cfg_attr(miri, ignore)]
+fn test_drain_leak_drops() {
+    let drops = Rc::new(Cell::new(0));
+    let mut stack = Stack::new();
+    let mut deque = Deque::new();
+    for i in 0..ITEMS {
+        stack.push(DropCounter::new(i, &drops));
+        deque.push_front(DropCounter::new(i, &drops));
+    }
+    // Leaked drains leak the range and what follows, without dropping anything twice
+    std::mem::forget(stack.drain(5..10));
+    std::mem::forget(deque.drain(5..10));
+    assert_eq!(drops.get(), 0);
+    // The stack counts positions from the top: the elements below the range are kept
+    drop((stack, deque));
+    assert_eq!(drops.get(), (ITEMS - 10) + 5);
+}
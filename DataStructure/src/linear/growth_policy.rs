@@ -0,0 +1,64 @@
+//! How the buffer-backed collections of [`linear`](super) grow once full.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests;
+
+/// Rule computing the capacity a full buffer grows to.
+///
+/// Whatever the rule returns, a buffer always grows by at least one slot.
+///
+/// ```
+/// use std::sync::Arc;
+/// use data_structure::linear::{GrowthPolicy, Stack};
+///
+/// let mut stack = Stack::with_capacity_and_policy(4, GrowthPolicy::Geometric(2.0));
+/// for i in 0..5 {
+///     stack.push(i);
+/// }
+/// assert_eq!(stack.buffer_size(), 8);
+///
+/// let policy = GrowthPolicy::Custom(Arc::new(|capacity| capacity + 100));
+/// assert_eq!(policy.next_capacity(8), Some(108));
+/// ```
+#[derive(Clone)]
+pub enum GrowthPolicy {
+    /// Add a fixed number of slots: pushing n elements costs O(n²) copies overall.
+    Linear(usize),
+    /// Multiply the capacity by a factor greater than 1: pushing n elements costs O(n) copies
+    /// overall.
+    Geometric(f64),
+    /// Compute the new capacity from the current one.
+    Custom(Arc<dyn Fn(usize) -> usize + Send + Sync>),
+}
+
+impl GrowthPolicy {
+    /// Capacity a full buffer of `capacity` slots grows to, `None` if it overflows `usize`.
+    pub fn next_capacity(&self, capacity: usize) -> Option<usize> {
+        let next_capacity = match self {
+            GrowthPolicy::Linear(increment) => capacity.checked_add(*increment)?,
+            GrowthPolicy::Geometric(factor) => {
+                let next_capacity = (capacity as f64 * factor).ceil();
+                // Float to integer casts saturate: anything reaching usize::MAX is an overflow
+                if next_capacity >= usize::MAX as f64 {
+                    return None;
+                }
+                next_capacity as usize
+            }
+            GrowthPolicy::Custom(next_capacity) => next_capacity(capacity),
+        };
+        Some(next_capacity.max(capacity.checked_add(1)?))
+    }
+}
+
+impl Debug for GrowthPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrowthPolicy::Linear(increment) => f.debug_tuple("Linear").field(increment).finish(),
+            GrowthPolicy::Geometric(factor) => f.debug_tuple("Geometric").field(factor).finish(),
+            GrowthPolicy::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
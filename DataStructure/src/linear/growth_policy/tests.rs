@@ -0,0 +1,32 @@
+use super::GrowthPolicy;
+use std::sync::Arc;
+
+#[test]
+fn test_linear() {
+    let policy = GrowthPolicy::Linear(10);
+    assert_eq!(policy.next_capacity(0), Some(10));
+    assert_eq!(policy.next_capacity(10), Some(20));
+    assert_eq!(policy.next_capacity(usize::MAX - 5), None);
+    // Never stays at the same capacity
+    assert_eq!(GrowthPolicy::Linear(0).next_capacity(7), Some(8));
+}
+
+#[test]
+fn test_geometric() {
+    let policy = GrowthPolicy::Geometric(1.5);
+    assert_eq!(policy.next_capacity(10), Some(15));
+    assert_eq!(policy.next_capacity(15), Some(23));
+    assert_eq!(policy.next_capacity(1), Some(2));
+    assert_eq!(policy.next_capacity(0), Some(1));
+    assert_eq!(GrowthPolicy::Geometric(2.0).next_capacity(usize::MAX / 2 + 1), None);
+    assert_eq!(GrowthPolicy::Geometric(0.5).next_capacity(10), Some(11));
+}
+
+#[test]
+fn test_custom() {
+    let policy = GrowthPolicy::Custom(Arc::new(|capacity| capacity * 4));
+    assert_eq!(policy.next_capacity(3), Some(12));
+    assert_eq!(policy.next_capacity(0), Some(1));
+    assert_eq!(format!("{:?}", policy), "Custom(..)");
+    assert_eq!(format!("{:?}", GrowthPolicy::Linear(10)), "Linear(10)");
+}
@@ -1,53 +1,78 @@
-use std::alloc::{alloc, dealloc, Layout};
-use std::fmt::{Display, Formatter};
-use std::mem::MaybeUninit;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::RangeBounds;
 use std::ptr;
-use std::ptr::NonNull;
 
-use crate::linear::buffer_guard::BufferGuard;
+use crate::error::{Error, Result};
+use crate::linear::growth_policy::GrowthPolicy;
+use crate::linear::range;
+use crate::linear::raw_buffer::RawBuffer;
+use crate::linear::ring_buffer::RingBuffer;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug)]
+pub use super::ring_iter::{Drain, IntoIter, Iter, IterMut};
+
 pub struct Queue<T> {
-    size: usize,
     length: usize,
-    buffer: NonNull<T>,
-    layout: Layout,
+    ring: RingBuffer<T>,
+    policy: GrowthPolicy,
 }
 
 pub const QUEUE_START_SIZE: usize = 10;
 pub const QUEUE_SIZE_INCREMENT: usize = 10;
 
-impl<T: Clone> Queue<T> {
+impl<T> Queue<T> {
     pub fn new() -> Queue<T> {
-        let layout = Layout::array::<T>(QUEUE_START_SIZE).expect("Layout error");
-        let ptr = NonNull::new(unsafe { alloc(layout) } as *mut T).expect("Allocation failed");
-        Queue {
-            size: QUEUE_START_SIZE,
-            length: 0,
-            buffer: ptr,
-            layout,
-        }
+        Self::with_capacity_and_policy(QUEUE_START_SIZE, GrowthPolicy::Linear(QUEUE_SIZE_INCREMENT))
     }
 
-    fn _increment_size(&mut self, increment: usize) {
-        let new_size = self.size + increment;
-        // Create a temporary new buffer that will be correctly dropped if something goes wrong
-        let buffer_guard = BufferGuard::<T>::new(new_size);
-        for i in 0..self.length {
-            unsafe {
-                ptr::write(
-                    buffer_guard.as_ptr().add(i),
-                    ptr::read(self.buffer.as_ptr().add(i)),
-                )
-            };
-        }
-        // Deallocate the old buffer and replace it with the new in the buffer guard
-        unsafe { dealloc(self.buffer.as_ptr() as *mut u8, self.layout) };
-        (self.buffer, self.layout) = buffer_guard.into_inner();
-        self.size = new_size;
+    /// Create an empty queue with room for `capacity` elements, growing according to `policy`
+    /// once full. [`new`](Self::new) is equivalent to a capacity of `QUEUE_START_SIZE` and a
+    /// [`GrowthPolicy::Linear`] policy of `QUEUE_SIZE_INCREMENT` slots.
+    pub fn with_capacity_and_policy(capacity: usize, policy: GrowthPolicy) -> Queue<T> {
+        Queue { length: 0, ring: RingBuffer::new(capacity), policy }
+    }
+
+    /// Create an empty queue with room for `capacity` elements, growing by
+    /// `QUEUE_SIZE_INCREMENT` slots once full
+    pub fn with_capacity(capacity: usize) -> Queue<T> {
+        Self::with_capacity_and_policy(capacity, GrowthPolicy::Linear(QUEUE_SIZE_INCREMENT))
+    }
+
+    /// Make room for at least `additional` more elements, like
+    /// [`Stack::reserve`](super::Stack::reserve) does.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Make room for exactly `additional` more elements, like
+    /// [`Stack::reserve_exact`](super::Stack::reserve_exact) does.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Fallible [`reserve`](Self::reserve): on error the queue is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        self.ring.try_reserve(&self.policy, self.length, additional)
+    }
+
+    /// Fallible [`reserve_exact`](Self::reserve_exact): on error the queue is left unchanged.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<()> {
+        self.ring.try_reserve_exact(self.length, additional)
+    }
+
+    /// Release the buffer slots beyond `min_capacity`, keeping at least room for the elements
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.ring.shrink_to(min_capacity, self.length);
+    }
+
+    /// Release every unused buffer slot
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
     }
 
     pub fn length(&self) -> usize {
@@ -57,75 +82,262 @@ impl<T: Clone> Queue<T> {
         self.length == 0
     }
     pub fn buffer_size(&self) -> usize {
-        self.size
+        self.ring.buffer.capacity()
     }
     pub fn buffer_is_full(&self) -> bool {
-        self.size == self.length
+        self.ring.buffer.capacity() == self.length
+    }
+    pub fn growth_policy(&self) -> &GrowthPolicy {
+        &self.policy
     }
 
     pub fn push(&mut self, item: T) {
         if self.buffer_is_full() {
-            self._increment_size(QUEUE_SIZE_INCREMENT);
+            self.ring.grow(&self.policy, self.length);
         }
         unsafe {
-            *self.buffer.as_ptr().add(self.length) = item;
+            ptr::write(self.ring.buffer.ptr(self.ring.tail), item);
         }
+        self.ring.tail = self.ring.buffer.wrap_add(self.ring.tail, 1);
         self.length += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
         if self.length > 0 {
+            let to_return = unsafe { ptr::read(self.ring.buffer.ptr(self.ring.head)) };
+            self.ring.head = self.ring.buffer.wrap_add(self.ring.head, 1);
             self.length -= 1;
-            let to_return = unsafe { ptr::read(self.buffer.as_ptr()) };
-            // Move all next elements by one position - O(N) Complexity with this simple implementation
-            for i in 0..self.length {
-                unsafe {
-                    ptr::write(
-                        self.buffer.as_ptr().add(i),
-                        ptr::read(self.buffer.as_ptr().add(i + 1)),
-                    );
-                }
-            }
             return Some(to_return);
         }
         None
     }
     pub fn head(&mut self) -> Option<&T> {
         if self.length > 0 {
-            return Some(unsafe { &*self.buffer.as_ptr() });
+            return Some(unsafe { &*self.ring.buffer.ptr(self.ring.head) });
         }
         None
     }
     pub fn head_mut(&mut self) -> Option<&mut T> {
         if self.length > 0 {
-            return Some(unsafe { &mut *self.buffer.as_ptr() });
+            return Some(unsafe { &mut *self.ring.buffer.ptr(self.ring.head) });
         }
         None
     }
+
+    /// [`pop`](Self::pop) failing with [`Error::Empty`] on an empty queue
+    pub fn try_pop(&mut self) -> Result<T> {
+        self.pop().ok_or(Error::Empty)
+    }
+
+    /// [`head`](Self::head) failing with [`Error::Empty`] on an empty queue
+    pub fn get_head(&self) -> Result<&T> {
+        if self.length == 0 {
+            return Err(Error::Empty);
+        }
+        Ok(unsafe { &*self.ring.buffer.ptr(self.ring.head) })
+    }
+
+    /// [`head_mut`](Self::head_mut) failing with [`Error::Empty`] on an empty queue
+    pub fn get_head_mut(&mut self) -> Result<&mut T> {
+        self.head_mut().ok_or(Error::Empty)
+    }
+
+    /// Iterate over the elements from the head to the tail
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.ring.buffer, self.ring.head, self.length)
+    }
+
+    /// Iterate mutably over the elements from the head to the tail
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&self.ring.buffer, self.ring.head, self.length)
+    }
+
+    /// Remove the elements in `range`, counting positions from the head, and iterate over them
+    /// from the head to the tail. Elements left in the range are removed when the iterator is
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range ends after the length or starts after its end.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = range::bounds(range, self.length);
+        Drain::new(
+            &mut self.ring.buffer,
+            self.ring.head,
+            &mut self.length,
+            &mut self.ring.tail,
+            range.start,
+            range.end,
+        )
+    }
 }
 
 impl<T: Display> Display for Queue<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Head -> [")?;
-        for i in 0..self.length {
-            write!(f, "{}", unsafe { self.buffer.as_ptr().add(i).read() })?;
-            if i != self.length - 1 {
+        for (i, item) in self.iter().enumerate() {
+            if i != 0 {
                 write!(f, ", ")?;
             }
+            write!(f, "{}", item)?;
         }
         write!(f, "] <- Tail")
     }
 }
 
+impl<T: Debug> Debug for Queue<T> {
+    /// Elements from the head to the tail
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for Queue<T> {
+    fn clone(&self) -> Self {
+        let mut clone = Queue {
+            length: 0,
+            ring: RingBuffer::new(self.ring.buffer.capacity()),
+            policy: self.policy.clone(),
+        };
+        // Elements are counted one at a time: if a clone panics the copied ones are dropped
+        for item in self.iter() {
+            clone.push(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T: PartialEq> PartialEq for Queue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Queue<T> {}
+
+impl<T: PartialOrd> PartialOrd for Queue<T> {
+    /// Lexicographic comparison of the elements from the head to the tail
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for Queue<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for Queue<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consume the queue, yielding its elements from the head to the tail
+    fn into_iter(mut self) -> IntoIter<T> {
+        // The queue keeps an empty buffer: its drop has nothing left to do
+        let length = mem::replace(&mut self.length, 0);
+        let head_index = mem::replace(&mut self.ring.head, 0);
+        self.ring.tail = 0;
+        let buffer = mem::replace(&mut self.ring.buffer, RawBuffer::new(0));
+        IntoIter::new(buffer, head_index, length)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Queue<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Queue::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<T> Extend<T> for Queue<T> {
+    /// Push the items at the tail, in order
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T> From<Vec<T>> for Queue<T> {
+    /// Take over the vector buffer without copying: its first element is the head
+    fn from(vec: Vec<T>) -> Self {
+        Queue {
+            length: vec.len(),
+            ring: RingBuffer::from_vec(vec),
+            policy: GrowthPolicy::Linear(QUEUE_SIZE_INCREMENT),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Queue<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T> From<Queue<T>> for Vec<T> {
+    /// Hand the queue buffer over, elements going from the head to the tail. Only a queue
+    /// wrapped around the end of its buffer needs a new allocation to be unrolled.
+    fn from(mut queue: Queue<T>) -> Self {
+        let length = mem::replace(&mut queue.length, 0);
+        let head_index = mem::replace(&mut queue.ring.head, 0);
+        queue.ring.tail = 0;
+        let buffer = mem::replace(&mut queue.ring.buffer, RawBuffer::new(0));
+        buffer.into_vec(head_index, length)
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for Queue<T> {
     fn drop(&mut self) {
+        // Drop every element to ensure custom and deep drops are executed, the buffer array is
+        // deallocated by its own drop
+        let (front, back) = self.ring.buffer.slice_ptrs(self.ring.head, self.length);
         unsafe {
-            // Drop every element to ensure custom and deep drops are executed
-            for i in 0..self.length {
-                ptr::drop_in_place(self.buffer.as_ptr().add(i));
-            }
-            // Drop the buffer array
-            dealloc(self.buffer.as_ptr() as _, self.layout);
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
         }
     }
 }
@@ -1,12 +1,18 @@
 use super::{Queue, QUEUE_SIZE_INCREMENT, QUEUE_START_SIZE};
+use crate::error::Error;
+use crate::linear::drop_tests::DropCounter;
+use crate::linear::GrowthPolicy;
+use crate::linear::test_utils::assert_clone_eq_hash;
+use std::cell::Cell;
+use std::rc::Rc;
 
 const QUEUE_ARRAY: [i32; 9] = [0, -1, 2, -3, 4, -5, 6, -7, 8];
-const QUEUE_DISPLAY: &'static str = "Head -> [0, -1, 2, -3, 4, -5, 6, -7, 8] <- Tail";
+const QUEUE_DISPLAY: &str = "Head -> [0, -1, 2, -3, 4, -5, 6, -7, 8] <- Tail";
 
 fn create_queue() -> Queue<i32> {
     let mut queue = Queue::<i32>::new();
-    for i in 0..QUEUE_ARRAY.len() {
-        queue.push(QUEUE_ARRAY[i]);
+    for &item in QUEUE_ARRAY.iter() {
+        queue.push(item);
     }
     queue
 }
@@ -37,8 +43,8 @@ fn test_pop() {
 #[test]
 fn test_push() {
     let mut queue = Queue::<i32>::new();
-    for i in 0..QUEUE_ARRAY.len() {
-        queue.push(QUEUE_ARRAY[i]);
+    for (i, &item) in QUEUE_ARRAY.iter().enumerate() {
+        queue.push(item);
         assert_eq!(queue.length(), i + 1);
     }
 }
@@ -48,17 +54,32 @@ fn test_head() {
     let mut queue = Queue::<i32>::new();
     assert!(queue.head().is_none());
     assert!(queue.head_mut().is_none());
-    for i in 0..QUEUE_ARRAY.len() {
-        queue.push(QUEUE_ARRAY[i]);
+    for &item in QUEUE_ARRAY.iter() {
+        queue.push(item);
         let head = queue.head();
         assert!(head.is_some());
         assert_eq!(*head.unwrap(), QUEUE_ARRAY[0]);
     }
-    let mut head = queue.head_mut().unwrap();
+    let head = queue.head_mut().unwrap();
     *head = 5000;
     assert_eq!(*queue.head().unwrap(), 5000);
 }
 
+#[test]
+fn test_empty_errors() {
+    fn pop_pair(queue: &mut Queue<i32>) -> Result<(i32, i32), Error> {
+        Ok((queue.try_pop()?, queue.try_pop()?))
+    }
+    let mut queue = create_queue();
+    assert_eq!(queue.get_head(), Ok(&0));
+    *queue.get_head_mut().unwrap() = 9;
+    assert_eq!(pop_pair(&mut queue), Ok((9, -1)));
+    while queue.pop().is_some() {}
+    assert_eq!(pop_pair(&mut queue), Err(Error::Empty));
+    assert_eq!(queue.get_head(), Err(Error::Empty));
+    assert_eq!(queue.get_head_mut(), Err(Error::Empty));
+}
+
 #[test]
 fn test_increment_buffer() {
     let mut queue = create_queue();
@@ -73,7 +94,266 @@ fn test_increment_buffer() {
 
 #[test]
 fn test_display() {
-    let mut queue = create_queue();
+    let queue = create_queue();
     let s = format!("{}", queue);
     assert_eq!(s, QUEUE_DISPLAY.to_string());
 }
+
+#[test]
+fn test_wrap_around() {
+    let mut queue = Queue::<i32>::new();
+    let mut expected = std::collections::VecDeque::new();
+    // Keep the queue shorter than its buffer so that head and tail wrap around many times
+    for i in 0..(QUEUE_START_SIZE as i32 * 5) {
+        queue.push(i);
+        expected.push_back(i);
+        if i % 3 != 0 {
+            assert_eq!(queue.pop(), expected.pop_front());
+        }
+        assert_eq!(queue.head().copied(), expected.front().copied());
+    }
+    assert_eq!(queue.length(), expected.len());
+    while let Some(item) = expected.pop_front() {
+        assert_eq!(queue.pop(), Some(item));
+    }
+    assert!(queue.pop().is_none());
+}
+
+#[test]
+fn test_increment_buffer_wrapped() {
+    let mut queue = create_queue();
+    // Move the head forward so that the next pushes wrap around the end of the buffer
+    for &item in QUEUE_ARRAY[..5].iter() {
+        assert_eq!(queue.pop(), Some(item));
+    }
+    let pushed = [100, 101, 102, 103, 104, 105, 106];
+    for item in pushed {
+        queue.push(item);
+    }
+    assert_eq!(queue.buffer_size(), QUEUE_START_SIZE + QUEUE_SIZE_INCREMENT);
+    assert_eq!(format!("{}", queue), "Head -> [-5, 6, -7, 8, 100, 101, 102, 103, 104, 105, 106] <- Tail");
+    let popped: Vec<i32> = std::iter::from_fn(|| queue.pop()).collect();
+    assert_eq!(popped, [-5, 6, -7, 8, 100, 101, 102, 103, 104, 105, 106]);
+}
+
+#[test]
+fn test_drop_wrapped() {
+    // One counter per element, so that each one is checked to be dropped exactly once
+    let drops: Vec<_> = (0..QUEUE_START_SIZE * 3 / 2).map(|_| Rc::new(Cell::new(0))).collect();
+    let mut queue = Queue::new();
+    for (i, drops) in drops.iter().enumerate().take(QUEUE_START_SIZE) {
+        queue.push(DropCounter::new(i, drops));
+    }
+    for i in 0..QUEUE_START_SIZE / 2 {
+        assert_eq!(queue.pop().unwrap().value, i.to_string());
+    }
+    for (i, drops) in drops.iter().enumerate().skip(QUEUE_START_SIZE) {
+        queue.push(DropCounter::new(i, drops));
+    }
+    assert!(queue.buffer_is_full());
+    assert_eq!(queue.head().unwrap().value, (QUEUE_START_SIZE / 2).to_string());
+    assert!(drops[QUEUE_START_SIZE / 2..].iter().all(|drops| drops.get() == 0));
+    drop(queue);
+    assert!(drops.iter().all(|drops| drops.get() == 1));
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let queue = create_queue();
+    // Same elements, but stored from another slot of the buffer
+    let mut other = Queue::new();
+    for i in 0..5 {
+        other.push(i);
+        other.pop();
+    }
+    for &item in QUEUE_ARRAY.iter() {
+        other.push(item);
+    }
+    assert_clone_eq_hash(&queue, &other, |clone| clone.push(100));
+    assert_eq!(format!("{}", queue), QUEUE_DISPLAY);
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut queue = Queue::<()>::new();
+    assert_eq!(queue.buffer_size(), usize::MAX);
+    for i in 0..1000 {
+        queue.push(());
+        queue.push(());
+        assert_eq!(queue.pop(), Some(()));
+        assert_eq!(queue.length(), i + 1);
+    }
+    assert!(!queue.buffer_is_full());
+    assert_eq!(queue.buffer_size(), usize::MAX);
+    assert_eq!(queue.head(), Some(&()));
+    assert!(queue.clone() == queue);
+    for _ in 0..1000 {
+        assert_eq!(queue.pop(), Some(()));
+    }
+    assert!(queue.pop().is_none());
+}
+
+#[test]
+fn test_with_capacity_and_policy() {
+    let mut queue = Queue::with_capacity_and_policy(4, GrowthPolicy::Geometric(1.5));
+    // Wrap around before the first growth
+    for i in 0..3 {
+        queue.push(i);
+    }
+    queue.pop();
+    queue.pop();
+    for &item in QUEUE_ARRAY.iter() {
+        queue.push(item);
+    }
+    assert_eq!(queue.buffer_size(), 14);
+    assert_eq!(format!("{}", queue), "Head -> [2, 0, -1, 2, -3, 4, -5, 6, -7, 8] <- Tail");
+    assert!(matches!(Queue::<i32>::new().growth_policy(), GrowthPolicy::Linear(QUEUE_SIZE_INCREMENT)));
+}
+
+#[test]
+fn test_reserve_and_shrink() {
+    let mut queue = Queue::with_capacity(12);
+    // Wrap the elements around the end of the buffer
+    for i in 0..8 {
+        queue.push(i);
+    }
+    for _ in 0..8 {
+        queue.pop();
+    }
+    for &item in QUEUE_ARRAY.iter() {
+        queue.push(item);
+    }
+    queue.shrink_to_fit();
+    assert_eq!(queue.buffer_size(), QUEUE_ARRAY.len());
+    assert!(queue.buffer_is_full());
+    assert_eq!(format!("{}", queue), QUEUE_DISPLAY);
+    queue.reserve_exact(3);
+    assert_eq!(queue.buffer_size(), QUEUE_ARRAY.len() + 3);
+    queue.reserve(4);
+    assert_eq!(queue.buffer_size(), QUEUE_ARRAY.len() + 3 + QUEUE_SIZE_INCREMENT);
+    queue.pop();
+    queue.push(10);
+    assert_eq!(format!("{}", queue), "Head -> [-1, 2, -3, 4, -5, 6, -7, 8, 10] <- Tail");
+    queue.shrink_to(0);
+    assert_eq!(queue.buffer_size(), QUEUE_ARRAY.len());
+
+    let mut empty = Queue::<i32>::new();
+    empty.shrink_to_fit();
+    assert_eq!(empty.buffer_size(), 0);
+    empty.push(1);
+    assert_eq!(empty.pop(), Some(1));
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut queue = create_queue();
+    assert_eq!(queue.try_reserve(usize::MAX), Err(Error::CapacityExceeded));
+    assert_eq!(queue.try_reserve_exact(usize::MAX / 4), Err(Error::CapacityExceeded));
+    assert_eq!(queue.buffer_size(), QUEUE_START_SIZE);
+    assert_eq!(queue.try_reserve_exact(1), Ok(()));
+    assert_eq!(queue.buffer_size(), QUEUE_START_SIZE);
+    assert_eq!(format!("{}", queue), QUEUE_DISPLAY);
+}
+
+/// Queue holding QUEUE_ARRAY wrapped around the end of its buffer
+fn create_wrapped_queue() -> Queue<i32> {
+    let mut queue = Queue::with_capacity(QUEUE_ARRAY.len() + 1);
+    for i in 0..5 {
+        queue.push(i);
+        queue.pop();
+    }
+    for &item in QUEUE_ARRAY.iter() {
+        queue.push(item);
+    }
+    queue
+}
+
+#[test]
+fn test_iter() {
+    let mut queue = create_wrapped_queue();
+    assert_eq!(queue.iter().copied().collect::<Vec<i32>>(), QUEUE_ARRAY);
+    assert!(queue.iter().rev().eq(QUEUE_ARRAY.iter().rev()));
+    assert_eq!(queue.iter().len(), QUEUE_ARRAY.len());
+    for item in &mut queue {
+        *item *= 10;
+    }
+    let multiplied: Vec<i32> = (&queue).into_iter().copied().collect();
+    assert_eq!(multiplied, QUEUE_ARRAY.map(|i| i * 10));
+    let mut iter = queue.iter_mut();
+    *iter.next_back().unwrap() = 1;
+    assert_eq!(iter.len(), QUEUE_ARRAY.len() - 1);
+    assert_eq!(queue.iter().next_back(), Some(&1));
+}
+
+#[test]
+fn test_into_iter() {
+    assert_eq!(create_wrapped_queue().into_iter().collect::<Vec<i32>>(), QUEUE_ARRAY);
+    let mut iter = create_wrapped_queue().into_iter();
+    assert_eq!(iter.next_back(), Some(8));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), QUEUE_ARRAY.len() - 2);
+    assert_eq!(iter.rev().collect::<Vec<i32>>(), [-7, 6, -5, 4, -3, 2, -1]);
+}
+
+#[test]
+fn test_drain() {
+    let mut queue = create_wrapped_queue();
+    assert_eq!(queue.drain(2..5).collect::<Vec<i32>>(), [2, -3, 4]);
+    assert_eq!(format!("{}", queue), "Head -> [0, -1, -5, 6, -7, 8] <- Tail");
+    assert_eq!(queue.drain(..1).next_back(), Some(0));
+    assert_eq!(format!("{}", queue), "Head -> [-1, -5, 6, -7, 8] <- Tail");
+    // The tail index follows the removal
+    queue.push(9);
+    assert_eq!(format!("{}", queue), "Head -> [-1, -5, 6, -7, 8, 9] <- Tail");
+    assert_eq!(queue.drain(..).collect::<Vec<i32>>(), [-1, -5, 6, -7, 8, 9]);
+    assert!(queue.is_empty());
+}
+
+#[test]
+// Leaks on purpose, which Miri reports as an error
+#[cfg_attr(miri, ignore)]
+fn test_drain_leaked() {
+    let mut queue = create_wrapped_queue();
+    std::mem::forget(queue.drain(3..4));
+    // The elements of the range and after are leaked, the ones before are kept
+    assert_eq!(format!("{}", queue), "Head -> [0, -1, 2] <- Tail");
+}
+
+#[test]
+fn test_from_iter_and_extend() {
+    let queue: Queue<i32> = QUEUE_ARRAY.into_iter().collect();
+    assert_eq!(format!("{}", queue), QUEUE_DISPLAY);
+    let mut queue = create_wrapped_queue();
+    queue.extend(&[9, 10]);
+    queue.extend(11..20);
+    assert!(queue.into_iter().eq(QUEUE_ARRAY.into_iter().chain(9..20)));
+}
+
+#[test]
+fn test_vec_conversions() {
+    let mut queue = Queue::from(QUEUE_ARRAY.to_vec());
+    assert_eq!(queue, create_queue());
+    // Pushing past the length of the vector grows the adopted buffer
+    queue.push(9);
+    assert_eq!(queue.pop(), Some(0));
+    assert_eq!(Queue::from([1, 2, 3]).to_string(), "Head -> [1, 2, 3] <- Tail");
+    // Elements wrapped around the end of the buffer come out in order
+    assert_eq!(Vec::from(create_wrapped_queue()), QUEUE_ARRAY);
+    let mut queue = create_wrapped_queue();
+    queue.pop();
+    assert_eq!(Vec::from(queue), QUEUE_ARRAY[1..]);
+    assert_eq!(Vec::from(Queue::<i32>::new()), []);
+}
+
+#[test]
+fn test_debug_and_ord() {
+    let queue = create_wrapped_queue();
+    assert_eq!(format!("{:?}", queue), "[0, -1, 2, -3, 4, -5, 6, -7, 8]");
+    let mut other = create_queue();
+    assert_eq!(other.cmp(&queue), std::cmp::Ordering::Equal);
+    other.push(0);
+    assert!(other > queue);
+    other.pop();
+    assert!(other < queue);
+    assert_eq!(Queue::<i32>::default(), Queue::new());
+}
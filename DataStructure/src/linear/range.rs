@@ -0,0 +1,35 @@
+//! Range arguments of the collections of [`linear`](super).
+
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::error::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// Indexes covered by `range` in a collection of `len` elements.
+///
+/// # Panics
+///
+/// Panics if the range ends after `len` or starts after its end.
+pub(crate) fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .unwrap_or_else(|| Error::IndexOutOfBounds { index: start, len }.raise()),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| Error::IndexOutOfBounds { index: end, len }.raise()),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        Error::IndexOutOfBounds { index: end, len }.raise();
+    }
+    assert!(start <= end, "range starts at {} but ends at {}", start, end);
+    start..end
+}
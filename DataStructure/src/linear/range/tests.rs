@@ -0,0 +1,32 @@
+use super::bounds;
+use std::ops::Bound;
+
+#[test]
+fn test_bounds() {
+    assert_eq!(bounds(.., 5), 0..5);
+    assert_eq!(bounds(1..3, 5), 1..3);
+    assert_eq!(bounds(1..=3, 5), 1..4);
+    assert_eq!(bounds(2.., 5), 2..5);
+    assert_eq!(bounds(..=4, 5), 0..5);
+    assert_eq!(bounds(5..5, 5), 5..5);
+    assert_eq!(bounds((Bound::Excluded(1), Bound::Unbounded), 5), 2..5);
+}
+
+#[test]
+#[should_panic(expected = "index 6 out of bounds (length is 5)")]
+fn test_bounds_end_out_of_bounds() {
+    bounds(2..6, 5);
+}
+
+#[test]
+#[should_panic(expected = "range starts at 3 but ends at 2")]
+fn test_bounds_start_after_end() {
+    #[allow(clippy::reversed_empty_ranges)]
+    bounds(3..2, 5);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds (length is 5)")]
+fn test_bounds_overflow() {
+    bounds(..=usize::MAX, 5);
+}
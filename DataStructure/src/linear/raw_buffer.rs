@@ -0,0 +1,189 @@
+//! Heap buffer shared by the buffer-backed collections of [`linear`](super).
+
+use std::alloc::{alloc, dealloc, Layout};
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
+use std::ptr::NonNull;
+
+use super::growth_policy::GrowthPolicy;
+use crate::error::{Error, Result};
+
+#[cfg(test)]
+mod tests;
+
+/// Uninitialized buffer of `capacity` slots of `T`.
+///
+/// It owns the allocation only: which slots hold an element is tracked by the collection using
+/// it, that is also in charge of dropping them. Elements are addressed by buffer index, and the
+/// `wrap_*` helpers let collections use the buffer as a circular array.
+///
+/// Zero-sized types never allocate and get a `usize::MAX` capacity, so they never need to grow.
+#[derive(Debug)]
+pub struct RawBuffer<T> {
+    ptr: NonNull<T>,
+    capacity: usize,
+    // The buffer logically owns values of T, for the drop check
+    _marker: PhantomData<T>,
+}
+
+// The buffer owns its slots like a `Box<[T]>` does: sending or sharing it sends or shares them
+unsafe impl<T: Send> Send for RawBuffer<T> {}
+unsafe impl<T: Sync> Sync for RawBuffer<T> {}
+
+impl<T> RawBuffer<T> {
+    const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+    pub fn new(capacity: usize) -> RawBuffer<T> {
+        Self::try_new(capacity).unwrap_or_else(|error| error.raise())
+    }
+
+    pub fn try_new(capacity: usize) -> Result<RawBuffer<T>> {
+        if Self::IS_ZST {
+            let capacity = usize::MAX;
+            return Ok(RawBuffer { ptr: NonNull::dangling(), capacity, _marker: PhantomData });
+        }
+        let layout = Layout::array::<T>(capacity).map_err(|_| Error::CapacityExceeded)?;
+        // Allocating zero bytes is not allowed: empty buffers just use a dangling pointer
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            NonNull::new(unsafe { alloc(layout) } as *mut T).ok_or(Error::AllocError { layout })?
+        };
+        Ok(RawBuffer { ptr, capacity, _marker: PhantomData })
+    }
+
+    /// Take over the allocation of `vec`: its elements are left at the start of the buffer
+    pub fn from_vec(vec: Vec<T>) -> RawBuffer<T> {
+        // Both allocate `Layout::array::<T>(capacity)` from the global allocator
+        let mut vec = ManuallyDrop::new(vec);
+        let capacity = if Self::IS_ZST { usize::MAX } else { vec.capacity() };
+        let ptr = NonNull::new(vec.as_mut_ptr()).unwrap();
+        RawBuffer { ptr, capacity, _marker: PhantomData }
+    }
+
+    /// Hand the allocation over to a vector of the `len` elements starting at buffer index
+    /// `head` (wrapping around the end), in the same order
+    pub fn into_vec(mut self, head: usize, len: usize) -> Vec<T> {
+        if len <= self.capacity - head {
+            // Contiguous: shift the elements in place
+            unsafe { ptr::copy(self.ptr(head), self.ptr(0), len) };
+        } else {
+            self.resize(self.capacity, head, len);
+        }
+        let buffer = ManuallyDrop::new(self);
+        unsafe { Vec::from_raw_parts(buffer.ptr.as_ptr(), len, buffer.capacity) }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Pointer to the slot at buffer index `index`
+    pub fn ptr(&self, index: usize) -> *mut T {
+        debug_assert!(index <= self.capacity);
+        unsafe { self.ptr.as_ptr().add(index) }
+    }
+
+    /// Buffer index `offset` slots after `index`, wrapping around the end of the buffer.
+    /// Computed without overflowing, as buffers of zero-sized types are `usize::MAX` long
+    pub fn wrap_add(&self, index: usize, offset: usize) -> usize {
+        let to_end = self.capacity - index;
+        if offset < to_end { index + offset } else { offset - to_end }
+    }
+
+    /// Buffer index `offset` slots before `index`, wrapping around the start of the buffer
+    pub fn wrap_sub(&self, index: usize, offset: usize) -> usize {
+        if offset <= index { index - offset } else { self.capacity - (offset - index) }
+    }
+
+    /// Raw slices of the `len` slots starting at buffer index `head`: the part before the end
+    /// of the buffer and the part wrapped around to its start (possibly empty)
+    pub fn slice_ptrs(&self, head: usize, len: usize) -> (*mut [T], *mut [T]) {
+        let to_end = self.capacity - head;
+        if len <= to_end {
+            (
+                ptr::slice_from_raw_parts_mut(self.ptr(head), len),
+                ptr::slice_from_raw_parts_mut(self.ptr(0), 0),
+            )
+        } else {
+            (
+                ptr::slice_from_raw_parts_mut(self.ptr(head), to_end),
+                ptr::slice_from_raw_parts_mut(self.ptr(0), len - to_end),
+            )
+        }
+    }
+
+    /// Move to a buffer of `capacity` slots. The `len` elements starting at buffer index `head`
+    /// (wrapping around the end) are moved to the start of the new buffer, in the same order.
+    pub fn resize(&mut self, capacity: usize, head: usize, len: usize) {
+        self.try_resize(capacity, head, len).unwrap_or_else(|error| error.raise())
+    }
+
+    pub fn try_resize(&mut self, capacity: usize, head: usize, len: usize) -> Result<()> {
+        assert!(len <= capacity, "resizing a buffer below its {} elements", len);
+        if Self::IS_ZST {
+            return Ok(());
+        }
+        let new_buffer = RawBuffer::<T>::try_new(capacity)?;
+        // Bitwise moves: the old buffer is left logically uninitialized
+        let (front, back) = self.slice_ptrs(head, len);
+        unsafe {
+            ptr::copy_nonoverlapping(front as *const T, new_buffer.ptr(0), front.len());
+            ptr::copy_nonoverlapping(back as *const T, new_buffer.ptr(front.len()), back.len());
+        }
+        // The old buffer is deallocated when dropped by the replacement
+        *self = new_buffer;
+        Ok(())
+    }
+
+    /// Resize to the capacity following the current one according to `policy`
+    pub fn grow(&mut self, policy: &GrowthPolicy, head: usize, len: usize) {
+        let capacity = policy.next_capacity(self.capacity);
+        self.resize(capacity.unwrap_or_else(|| Error::CapacityExceeded.raise()), head, len);
+    }
+
+    /// Make room for at least `additional` elements after the `len` ones, growing at least as
+    /// much as `policy` would so that repeated reservations stay amortized
+    pub fn try_reserve(
+        &mut self,
+        policy: &GrowthPolicy,
+        head: usize,
+        len: usize,
+        additional: usize,
+    ) -> Result<()> {
+        let required = len.checked_add(additional).ok_or(Error::CapacityExceeded)?;
+        if required <= self.capacity {
+            return Ok(());
+        }
+        let capacity = policy.next_capacity(self.capacity).map_or(required, |c| c.max(required));
+        self.try_resize(capacity, head, len)
+    }
+
+    /// Make room for exactly `additional` elements after the `len` ones, if not already there
+    pub fn try_reserve_exact(&mut self, head: usize, len: usize, additional: usize) -> Result<()> {
+        let required = len.checked_add(additional).ok_or(Error::CapacityExceeded)?;
+        if required <= self.capacity {
+            return Ok(());
+        }
+        self.try_resize(required, head, len)
+    }
+
+    /// Release the slots beyond `min_capacity`, never going below the `len` elements
+    pub fn shrink_to(&mut self, min_capacity: usize, head: usize, len: usize) {
+        let capacity = min_capacity.max(len);
+        if capacity < self.capacity {
+            self.resize(capacity, head, len);
+        }
+    }
+}
+
+impl<T> Drop for RawBuffer<T> {
+    fn drop(&mut self) {
+        // Zero-sized types and empty buffers have nothing allocated
+        if !Self::IS_ZST && self.capacity != 0 {
+            let layout = Layout::array::<T>(self.capacity).unwrap();
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use super::RawBuffer;
+use crate::linear::growth_policy::GrowthPolicy;
+use crate::error::Error;
+
+/// Buffer of `capacity` slots holding `values` from buffer index `head`, wrapping around the end
+fn create_wrapped_buffer(capacity: usize, head: usize, values: &[i32]) -> RawBuffer<i32> {
+    let buffer = RawBuffer::<i32>::new(capacity);
+    for (i, &value) in values.iter().enumerate() {
+        unsafe { buffer.ptr(buffer.wrap_add(head, i)).write(value) };
+    }
+    buffer
+}
+
+fn read_values(buffer: &RawBuffer<i32>, head: usize, len: usize) -> Vec<i32> {
+    let (front, back) = buffer.slice_ptrs(head, len);
+    unsafe { (*front).iter().chain((*back).iter()).copied().collect() }
+}
+
+#[test]
+fn test_new() {
+    assert_eq!(RawBuffer::<i32>::new(10).capacity(), 10);
+    assert_eq!(RawBuffer::<i32>::new(0).capacity(), 0);
+    assert_eq!(RawBuffer::<()>::new(10).capacity(), usize::MAX);
+    assert_eq!(RawBuffer::<i32>::try_new(usize::MAX).err(), Some(Error::CapacityExceeded));
+}
+
+#[test]
+fn test_wrap() {
+    let buffer = RawBuffer::<i32>::new(6);
+    assert_eq!(buffer.wrap_add(2, 3), 5);
+    assert_eq!(buffer.wrap_add(2, 4), 0);
+    assert_eq!(buffer.wrap_add(5, 6), 5);
+    assert_eq!(buffer.wrap_sub(2, 2), 0);
+    assert_eq!(buffer.wrap_sub(2, 3), 5);
+    let zst_buffer = RawBuffer::<()>::new(0);
+    assert_eq!(zst_buffer.wrap_add(usize::MAX - 1, 1), 0);
+    assert_eq!(zst_buffer.wrap_sub(0, 1), usize::MAX - 1);
+}
+
+#[test]
+fn test_slice_ptrs() {
+    let buffer = create_wrapped_buffer(6, 4, &[1, 2, 3, 4, 5]);
+    let (front, back) = buffer.slice_ptrs(4, 5);
+    assert_eq!((front.len(), back.len()), (2, 3));
+    let (front, back) = buffer.slice_ptrs(0, 3);
+    assert_eq!((front.len(), back.len()), (3, 0));
+    assert_eq!(read_values(&buffer, 4, 5), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_resize_unrolls() {
+    let mut buffer = create_wrapped_buffer(6, 4, &[1, 2, 3, 4, 5]);
+    buffer.resize(10, 4, 5);
+    assert_eq!(buffer.capacity(), 10);
+    assert_eq!(read_values(&buffer, 0, 5), [1, 2, 3, 4, 5]);
+    buffer.grow(&GrowthPolicy::Linear(5), 0, 5);
+    assert_eq!(buffer.capacity(), 15);
+    assert_eq!(read_values(&buffer, 0, 5), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_resize_shrinks() {
+    let mut buffer = create_wrapped_buffer(10, 8, &[1, 2, 3, 4]);
+    buffer.resize(4, 8, 4);
+    assert_eq!(buffer.capacity(), 4);
+    assert_eq!(read_values(&buffer, 0, 4), [1, 2, 3, 4]);
+    buffer.resize(0, 0, 0);
+    assert_eq!(buffer.capacity(), 0);
+}
+
+#[test]
+#[should_panic(expected = "resizing a buffer below its 4 elements")]
+fn test_resize_below_length() {
+    let mut buffer = create_wrapped_buffer(10, 0, &[1, 2, 3, 4]);
+    buffer.resize(3, 0, 4);
+}
+
+#[test]
+fn test_reserve() {
+    let mut buffer = create_wrapped_buffer(6, 4, &[1, 2, 3, 4, 5]);
+    let policy = GrowthPolicy::Geometric(2.0);
+    // Enough room already: nothing moves
+    buffer.try_reserve(&policy, 4, 5, 1).unwrap();
+    assert_eq!(buffer.capacity(), 6);
+    // The policy asks for more than required
+    buffer.try_reserve(&policy, 4, 5, 2).unwrap();
+    assert_eq!(buffer.capacity(), 12);
+    assert_eq!(read_values(&buffer, 0, 5), [1, 2, 3, 4, 5]);
+    // The requirement asks for more than the policy
+    buffer.try_reserve(&policy, 0, 5, 50).unwrap();
+    assert_eq!(buffer.capacity(), 55);
+    buffer.try_reserve_exact(0, 5, 60).unwrap();
+    assert_eq!(buffer.capacity(), 65);
+    assert_eq!(read_values(&buffer, 0, 5), [1, 2, 3, 4, 5]);
+    assert_eq!(buffer.try_reserve_exact(0, 5, usize::MAX), Err(Error::CapacityExceeded));
+    assert_eq!(buffer.try_reserve(&policy, 0, 5, usize::MAX - 5), Err(Error::CapacityExceeded));
+    assert_eq!(buffer.capacity(), 65);
+}
+
+#[test]
+fn test_shrink_to() {
+    let mut buffer = create_wrapped_buffer(10, 8, &[1, 2, 3, 4]);
+    buffer.shrink_to(6, 8, 4);
+    assert_eq!(buffer.capacity(), 6);
+    assert_eq!(read_values(&buffer, 0, 4), [1, 2, 3, 4]);
+    // Never grows, never drops elements
+    buffer.shrink_to(8, 0, 4);
+    assert_eq!(buffer.capacity(), 6);
+    buffer.shrink_to(0, 0, 4);
+    assert_eq!(buffer.capacity(), 4);
+    assert_eq!(read_values(&buffer, 0, 4), [1, 2, 3, 4]);
+    let mut zst_buffer = RawBuffer::<()>::new(0);
+    zst_buffer.shrink_to(0, 0, 3);
+    assert_eq!(zst_buffer.capacity(), usize::MAX);
+}
+
+#[test]
+fn test_vec_round_trip() {
+    let mut vec = Vec::with_capacity(8);
+    vec.extend([1, 2, 3]);
+    let vec_ptr = vec.as_ptr();
+    let buffer = RawBuffer::from_vec(vec);
+    assert_eq!(buffer.capacity(), 8);
+    assert_eq!(buffer.ptr(0) as *const i32, vec_ptr);
+    assert_eq!(read_values(&buffer, 0, 3), [1, 2, 3]);
+    // Contiguous elements are shifted in place
+    let buffer = create_wrapped_buffer(6, 2, &[1, 2, 3, 4]);
+    let buffer_ptr = buffer.ptr(0) as *const i32;
+    let vec = buffer.into_vec(2, 4);
+    assert_eq!(vec, [1, 2, 3, 4]);
+    assert_eq!((vec.as_ptr(), vec.capacity()), (buffer_ptr, 6));
+    // Wrapped elements are unrolled
+    let buffer = create_wrapped_buffer(6, 4, &[1, 2, 3, 4, 5]);
+    assert_eq!(buffer.into_vec(4, 5), [1, 2, 3, 4, 5]);
+    assert_eq!(RawBuffer::<i32>::new(0).into_vec(0, 0), []);
+    assert_eq!(RawBuffer::<()>::from_vec(vec![(); 3]).into_vec(0, 3), [(); 3]);
+}
@@ -0,0 +1,75 @@
+//! Circular array shared by [`Queue`](super::Queue) and [`Deque`](super::Deque).
+
+use super::growth_policy::GrowthPolicy;
+use super::raw_buffer::RawBuffer;
+use crate::error::Result;
+
+/// [`RawBuffer`] used as a circular array, with the buffer indexes of its first element and of
+/// the first free slot after its last element.
+///
+/// Like the raw buffer, it only owns the allocation: the collection tracks the number of
+/// elements, passes it to the resizing methods and drops the elements. Resizes move the
+/// elements to the first slots of the new buffer, and the indexes follow them.
+pub struct RingBuffer<T> {
+    pub buffer: RawBuffer<T>,
+    pub head: usize,
+    pub tail: usize,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize) -> RingBuffer<T> {
+        RingBuffer { buffer: RawBuffer::new(capacity), head: 0, tail: 0 }
+    }
+
+    /// Take over the allocation of `vec`: its first element is the head
+    pub fn from_vec(vec: Vec<T>) -> RingBuffer<T> {
+        let length = vec.len();
+        let buffer = RawBuffer::from_vec(vec);
+        let tail = buffer.wrap_add(0, length);
+        RingBuffer { buffer, head: 0, tail }
+    }
+
+    /// Grow the full buffer of `len` elements according to `policy`
+    pub fn grow(&mut self, policy: &GrowthPolicy, len: usize) {
+        self.buffer.grow(policy, self.head, len);
+        self.head = 0;
+        self.tail = self.buffer.wrap_add(0, len);
+    }
+
+    /// See [`RawBuffer::try_reserve`]
+    pub fn try_reserve(
+        &mut self,
+        policy: &GrowthPolicy,
+        len: usize,
+        additional: usize,
+    ) -> Result<()> {
+        let capacity = self.buffer.capacity();
+        self.buffer.try_reserve(policy, self.head, len, additional)?;
+        self.sync_indexes(capacity, len);
+        Ok(())
+    }
+
+    /// See [`RawBuffer::try_reserve_exact`]
+    pub fn try_reserve_exact(&mut self, len: usize, additional: usize) -> Result<()> {
+        let capacity = self.buffer.capacity();
+        self.buffer.try_reserve_exact(self.head, len, additional)?;
+        self.sync_indexes(capacity, len);
+        Ok(())
+    }
+
+    /// See [`RawBuffer::shrink_to`]
+    pub fn shrink_to(&mut self, min_capacity: usize, len: usize) {
+        let capacity = self.buffer.capacity();
+        self.buffer.shrink_to(min_capacity, self.head, len);
+        self.sync_indexes(capacity, len);
+    }
+
+    /// Follow the `len` elements to the first slots if the capacity moved away from
+    /// `old_capacity`, that is if the buffer was resized
+    fn sync_indexes(&mut self, old_capacity: usize, len: usize) {
+        if self.buffer.capacity() != old_capacity {
+            self.head = 0;
+            self.tail = self.buffer.wrap_add(0, len);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Iterators over the circular buffers of [`Queue`](super::Queue) and [`Deque`](super::Deque),
+//! yielding elements from the head to the tail.
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr;
+use std::slice;
+
+use super::raw_buffer::RawBuffer;
+
+/// Borrowing iterator returned by `iter` on a [`Queue`](super::Queue) or a
+/// [`Deque`](super::Deque).
+#[derive(Clone)]
+pub struct Iter<'a, T> {
+    // Elements up to the end of the buffer, then the ones wrapped around to its start
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+/// Mutably borrowing iterator returned by `iter_mut` on a [`Queue`](super::Queue) or a
+/// [`Deque`](super::Deque).
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+/// Owning iterator returned by `into_iter` on a [`Queue`](super::Queue) or a
+/// [`Deque`](super::Deque).
+pub struct IntoIter<T> {
+    buffer: RawBuffer<T>,
+    // Buffer index of the first element not yielded yet, and number of elements left
+    head: usize,
+    length: usize,
+}
+
+/// Iterator removing a range of elements, returned by `drain` on a [`Queue`](super::Queue) or a
+/// [`Deque`](super::Deque).
+///
+/// Elements of the range not yielded are dropped with the iterator, then the elements after the
+/// range move towards the head to close the gap.
+///
+/// Like the mutable reference to the collection it stands for, a drain can only be sent to
+/// another thread along with its elements:
+///
+/// ```compile_fail
+/// use std::sync::{Mutex, MutexGuard};
+/// use data_structure::linear::deque::Drain;
+///
+/// fn assert_send<T: Send>() {}
+/// assert_send::<Drain<'_, MutexGuard<'_, i32>>>();
+/// ```
+///
+/// and only be shared along with them:
+///
+/// ```compile_fail
+/// use std::cell::Cell;
+/// use data_structure::linear::queue::Drain;
+///
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<Drain<'_, Cell<i32>>>();
+/// ```
+pub struct Drain<'a, T> {
+    // First slot and capacity of the buffer of the drained collection, borrowed for 'a
+    buffer: *mut T,
+    capacity: usize,
+    // Fields of the drained collection: its head does not move, its length and tail are set
+    // back when the gap is closed
+    head: usize,
+    length: &'a mut usize,
+    tail: &'a mut usize,
+    // Positions from the head of the elements not yielded yet
+    start: usize,
+    end: usize,
+    // Position past the drained range, and number of elements after it
+    drain_end: usize,
+    tail_length: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// Same as the mutable reference to the collection the drain stands for
+unsafe impl<T: Send> Send for Drain<'_, T> {}
+unsafe impl<T: Sync> Sync for Drain<'_, T> {}
+
+impl<'a, T> Iter<'a, T> {
+    /// Iterate over the `length` elements starting at buffer index `head`
+    pub(super) fn new(buffer: &'a RawBuffer<T>, head: usize, length: usize) -> Self {
+        let (front, back) = buffer.slice_ptrs(head, length);
+        unsafe { Iter { front: (*front).iter(), back: (*back).iter() } }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.front.len() + self.back.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Iterate over the `length` elements starting at buffer index `head`. The buffer is
+    /// borrowed mutably for `'a` by the collection creating the iterator.
+    pub(super) fn new(buffer: &'a RawBuffer<T>, head: usize, length: usize) -> Self {
+        let (front, back) = buffer.slice_ptrs(head, length);
+        unsafe { IterMut { front: (*front).iter_mut(), back: (*back).iter_mut() } }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.front.len() + self.back.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> IntoIter<T> {
+    /// Take ownership of the `length` elements starting at buffer index `head`
+    pub(super) fn new(buffer: RawBuffer<T>, head: usize, length: usize) -> Self {
+        IntoIter { buffer, head, length }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        let item = unsafe { ptr::read(self.buffer.ptr(self.head)) };
+        self.head = self.buffer.wrap_add(self.head, 1);
+        self.length -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+        let index = self.buffer.wrap_add(self.head, self.length);
+        Some(unsafe { ptr::read(self.buffer.ptr(index)) })
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // Drop the elements not yielded, the buffer array is deallocated by its own drop
+        let (front, back) = self.buffer.slice_ptrs(self.head, self.length);
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<'a, T> Drain<'a, T> {
+    /// Drain the positions `start..end` of the collection whose fields are given
+    pub(super) fn new(
+        buffer: &'a mut RawBuffer<T>,
+        head: usize,
+        length: &'a mut usize,
+        tail: &'a mut usize,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        // Until the drain is dropped the collection ends before the range: if the drain is
+        // leaked, the elements after are leaked too instead of being exposed after being moved
+        let tail_length = *length - end;
+        *length = start;
+        *tail = buffer.wrap_add(head, start);
+        let (capacity, buffer) = (buffer.capacity(), buffer.ptr(0));
+        let drain_end = end;
+        let _marker = PhantomData;
+        Drain { buffer, capacity, head, length, tail, start, end, drain_end, tail_length, _marker }
+    }
+
+    /// Buffer index of the element at `position` from the head, wrapping around the end of the
+    /// buffer like [`RawBuffer::wrap_add`]
+    fn index(&self, position: usize) -> usize {
+        let to_end = self.capacity - self.head;
+        if position < to_end { self.head + position } else { position - to_end }
+    }
+
+    /// Pointer to the buffer slot of the element at `position` from the head
+    fn slot(&self, position: usize) -> *mut T {
+        unsafe { self.buffer.add(self.index(position)) }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(unsafe { ptr::read(self.slot(self.start - 1)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { ptr::read(self.slot(self.end)) })
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        /// Closes the gap even if dropping one of the remaining elements panics
+        struct CloseGap<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<T> Drop for CloseGap<'_, '_, T> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                let gap_start = *drain.length;
+                // Moving towards the head one element at a time, as both ranges may wrap around
+                for i in 0..drain.tail_length {
+                    let (from, to) = (drain.slot(drain.drain_end + i), drain.slot(gap_start + i));
+                    unsafe { ptr::copy(from, to, 1) };
+                }
+                *drain.length += drain.tail_length;
+                *drain.tail = drain.index(*drain.length);
+            }
+        }
+
+        let guard = CloseGap(self);
+        let drain = &mut *guard.0;
+        while drain.start != drain.end {
+            drain.start += 1;
+            unsafe { ptr::drop_in_place(drain.slot(drain.start - 1)) };
+        }
+    }
+}
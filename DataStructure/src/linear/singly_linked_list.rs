@@ -1,25 +1,44 @@
-pub mod node;
+mod cursor;
+mod iter;
+mod node;
+#[cfg(test)]
 mod tests;
 
+use crate::error::{Error, Result};
+use crate::linear::range;
 use node::Node;
-use std::any::type_name;
-use std::fmt::{write, Debug, Display, Formatter};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem;
 use std::ptr::NonNull;
-use std::ops::{Index, IndexMut};
+use std::ops::{Index, IndexMut, RangeBounds};
+
+pub use cursor::CursorMut;
+pub use iter::{Drain, ExtractIf, IntoIter, Iter, IterMut};
 
-#[derive(Debug)]
 pub struct SinglyLinkedList<T> {
-    head: Option<Box<Node<T>>>,
+    // Nodes are allocated as boxes and linked through raw pointers, so that the tail can alias
+    // the last one
+    head: Option<NonNull<Node<T>>>,
     tail: Option<NonNull<Node<T>>>,
     length: usize,
+    // The list logically owns boxed nodes of T, for the drop check
+    _marker: PhantomData<Box<Node<T>>>,
 }
 
-impl<T: PartialEq> SinglyLinkedList<T> {
+// The list owns its nodes like a `Box<Node<T>>` chain would, only the links are raw pointers
+unsafe impl<T: Send> Send for SinglyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for SinglyLinkedList<T> {}
+
+impl<T> SinglyLinkedList<T> {
     pub fn new() -> SinglyLinkedList<T> {
         SinglyLinkedList {
             tail: None,
             head: None,
             length: 0,
+            _marker: PhantomData,
         }
     }
 
@@ -34,214 +53,459 @@ impl<T: PartialEq> SinglyLinkedList<T> {
     /// # Examples
     ///
     /// ```
-    /// let mut ll = singly_linked_list::<i32>::new();
+    /// use data_structure::linear::SinglyLinkedList;
+    ///
+    /// let mut ll = SinglyLinkedList::<i32>::new();
     /// ll.push_back(5); // Add value '5' as last element of the list
     /// ```
     pub fn push_back(&mut self, item: T) {
-        let mut new_node = Box::new(Node::new(item));
-        let new_nod_ptr = NonNull::new(&mut *new_node);
-        if self.tail.is_none() {
-            self.head = Some(new_node);
-        } else {
-            unsafe {
-                self.tail.unwrap().as_mut().next = Some(new_node);
-            }
+        let new_node = NonNull::from(Box::leak(Box::new(Node::new(item))));
+        match self.tail {
+            None => self.head = Some(new_node),
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(new_node) },
         }
-        self.tail = new_nod_ptr;
+        self.tail = Some(new_node);
         self.length += 1;
     }
 
     pub fn pop_back(&mut self) -> Option<T> {
-        if self.length > 0 {
-            self.length -= 1;
-
-            if let Some(node) = &self.head {
-                if node.next.is_none() {
-                    self.tail = None;
-                    return Some(self.head.take()?.value);
-                }
-            }
-
-            let mut second_last_node: _ = self.head.as_mut().unwrap();
-            let mut last_node = &second_last_node.next;
-
-            while let Some(last_node_box) = last_node {
-                if last_node_box.next.is_some() {
-                    second_last_node = second_last_node.next.as_mut().unwrap();
-                } else {
-                    break;
-                }
-                last_node = &second_last_node.next;
-            }
-            self.tail = Some(NonNull::from(&mut **second_last_node));
-            let removed = second_last_node.next.take().unwrap();
-            return Some(removed.value);
+        let old_tail = self.tail?;
+        if self.length == 1 {
+            self.head = None;
+            self.tail = None;
+        } else {
+            // No link back from the tail: walk to the node before it
+            let new_tail = self.node_at(self.length - 2);
+            unsafe { (*new_tail.as_ptr()).next = None };
+            self.tail = Some(new_tail);
         }
-        None
+        self.length -= 1;
+        Some(unsafe { Box::from_raw(old_tail.as_ptr()) }.value)
     }
 
     pub fn push_front(&mut self, item: T) {
         let mut new_node = Box::new(Node::new(item));
-        let new_nod_ptr = NonNull::new(&mut *new_node);
+        new_node.next = self.head;
+        let new_node = NonNull::from(Box::leak(new_node));
         if self.tail.is_none() {
-            self.tail = new_nod_ptr;
-        } else {
-            new_node.next = self.head.take();
+            self.tail = Some(new_node);
         }
         self.head = Some(new_node);
         self.length += 1;
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
-        if self.length > 0 {
-            self.length -= 1;
-
-            if let Some(node) = &self.head {
-                if node.next.is_none() {
-                    self.tail = None;
-                    return Some(self.head.take()?.value);
-                }
-            }
-            let old_head = self.head.take()?;
-            self.head = old_head.next;
-            return Some(old_head.value);
+        let old_head = unsafe { Box::from_raw(self.head?.as_ptr()) };
+        self.head = old_head.next;
+        if self.head.is_none() {
+            self.tail = None;
         }
-        None
+        self.length -= 1;
+        Some(old_head.value)
     }
 
-    pub fn get(&self, index: usize) -> Option<T> where T: Clone {
-        if index < self.length {
-            let mut node_box = self.head.as_ref().unwrap();
-            for _ in 0..index {
-                node_box = node_box.next.as_ref().unwrap();
-            }
-            return Some(node_box.value.clone());
-        }
-        None
+    /// [`pop_back`](Self::pop_back) failing with [`Error::Empty`] on an empty list
+    pub fn try_pop_back(&mut self) -> Result<T> {
+        self.pop_back().ok_or(Error::Empty)
     }
 
-    pub fn get_ref(&self, index: usize) -> Option<&T> {
-        if index < self.length {
-            let mut node_box = self.head.as_ref().unwrap();
-            for _ in 0..index {
-                node_box = node_box.next.as_ref().unwrap();
-            }
-            return Some(&node_box.value);
-        }
-        None
+    /// [`pop_front`](Self::pop_front) failing with [`Error::Empty`] on an empty list
+    pub fn try_pop_front(&mut self) -> Result<T> {
+        self.pop_front().ok_or(Error::Empty)
     }
 
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+    pub fn get(&self, index: usize) -> Result<T> where T: Clone {
+        Ok(self.get_ref(index)?.clone())
+    }
+
+    pub fn get_ref(&self, index: usize) -> Result<&T> {
         if index < self.length {
-            let mut node_box = self.head.as_mut().unwrap();
-            for _ in 0..index {
-                node_box = node_box.next.as_mut().unwrap();
-            }
-            return Some(&mut node_box.value);
+            return Ok(unsafe { &(*self.node_at(index).as_ptr()).value });
         }
-        None
+        Err(Error::IndexOutOfBounds { index, len: self.length })
     }
 
-    pub fn find(&self, value: &T) -> Option<usize> {
-        let mut node_opt = self.head.as_ref();
-        for i in 0..self.length {
-            let node_box = node_opt.unwrap();
-            if node_box.value == *value {
-                return Some(i);
-            }
-            node_opt = node_box.next.as_ref();
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T> {
+        if index < self.length {
+            return Ok(unsafe { &mut (*self.node_at(index).as_ptr()).value });
         }
-        None
+        Err(Error::IndexOutOfBounds { index, len: self.length })
     }
 
-    pub fn remove(&mut self, index: usize) -> Option<T> {
+    pub fn remove(&mut self, index: usize) -> Result<T> {
         if index < self.length {
-            // If index is 0, you're popping the head
-            if index == 0 {
-                return self.pop_front();
-            }
-
-            // Else if index is the tail pop back
-            if index == self.length - 1 {
-                return self.pop_back();
-            }
+            // Bypass the node to remove from its predecessor, or from the head link for the head
+            let pre_node = index.checked_sub(1).map(|index| self.node_at(index));
+            unsafe {
+                let link = match pre_node {
+                    None => &mut self.head,
+                    Some(pre_node) => &mut (*pre_node.as_ptr()).next,
+                };
+                let to_remove = link.unwrap();
+                *link = (*to_remove.as_ptr()).next;
+                if self.tail == Some(to_remove) {
+                    self.tail = pre_node;
+                }
 
-            // Else iterate to reach predecessor of node to remove
-            let mut pre_node_opt: _ = self.head.as_mut();
-            for i in 0..index - 1 {
-                pre_node_opt = pre_node_opt.unwrap().next.as_mut();
+                // Update length of the list
+                self.length -= 1;
+                return Ok(Box::from_raw(to_remove.as_ptr()).value);
             }
-            // Unwrap and change ownerships to bypass node to remove
-            let pre_node_box = pre_node_opt.unwrap();
-            let to_remove = pre_node_box.next.take().unwrap();
-            pre_node_box.next = to_remove.next; // Moving successor of node to remove as next of the predecessor to remove
-
-            // Update length of the list
-            self.length -= 1;
-            return Some(to_remove.value);
         }
-        None
+        Err(Error::IndexOutOfBounds { index, len: self.length })
     }
 
     pub fn len(&self) -> usize {
         self.length
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
     pub fn print(&self) where T:Display {
         if self.length > 0 {
-            let mut node_opt = &self.head;
-            while let Some(node) = node_opt {
-                print!("({})", node.value);
-                if node.next.is_some() {
+            for (i, item) in self.iter().enumerate() {
+                if i != 0 {
                     print!(" -> ");
                 }
-                node_opt = &node.next;
+                print!("({})", item);
             }
-            println!("");
+            println!();
         } else {
             println!("()");
         }
     }
+
+    /// Iterate over the elements from the head to the tail
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.head, self.length)
+    }
+
+    /// Iterate mutably over the elements from the head to the tail
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.head, self.length)
+    }
+
+    /// Remove the elements in `range`, counting positions from the head, and iterate over them
+    /// from the head to the tail. The range is unlinked from the list right away: elements left
+    /// in it are dropped with the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range ends after the length or starts after its end.
+    ///
+    /// ```
+    /// use data_structure::linear::SinglyLinkedList;
+    ///
+    /// let mut list = SinglyLinkedList::from([0, 1, 2, 3, 4]);
+    /// let drained: Vec<i32> = list.drain(1..3).collect();
+    /// assert_eq!(drained, [1, 2]);
+    /// assert_eq!(list.to_string(), "[0, 3, 4]");
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = range::bounds(range, self.length);
+        let count = range.end - range.start;
+        if count == 0 {
+            return Drain::new(None, 0);
+        }
+        let pre_node = range.start.checked_sub(1).map(|index| self.node_at(index));
+        unsafe {
+            let first = match pre_node {
+                None => self.head,
+                Some(pre_node) => (*pre_node.as_ptr()).next,
+            };
+            let mut last = first.unwrap();
+            for _ in 1..count {
+                last = (*last.as_ptr()).next.unwrap();
+            }
+            // Link the nodes around the range, that ends up on its own
+            let after = (*last.as_ptr()).next.take();
+            match pre_node {
+                None => self.head = after,
+                Some(pre_node) => (*pre_node.as_ptr()).next = after,
+            }
+            if after.is_none() {
+                self.tail = pre_node;
+            }
+            self.length -= count;
+            Drain::new(first, count)
+        }
+    }
+
+    /// Move every element of `other` after the tail, in the same order, leaving `other` empty.
+    /// Only the tail of this list and the head of `other` are relinked, in O(1).
+    ///
+    /// ```
+    /// use data_structure::linear::SinglyLinkedList;
+    ///
+    /// let mut list = SinglyLinkedList::from([1, 2]);
+    /// let mut other = SinglyLinkedList::from([3, 4]);
+    /// list.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(list.to_string(), "[1, 2, 3, 4]");
+    /// ```
+    pub fn append(&mut self, other: &mut SinglyLinkedList<T>) {
+        let mut other = mem::take(other);
+        let Some(first) = other.head.take() else {
+            return;
+        };
+        match self.tail {
+            None => self.head = Some(first),
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(first) },
+        }
+        self.tail = other.tail.take();
+        self.length += mem::take(&mut other.length);
+    }
+
+    /// Split the list in two at position `at` from the head: the elements from `at` on are
+    /// moved to the returned list. Reaching position `at` walks from the head, then the split
+    /// itself is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> SinglyLinkedList<T> {
+        if at > self.length {
+            Error::IndexOutOfBounds { index: at, len: self.length }.raise();
+        }
+        match at.checked_sub(1) {
+            None => mem::take(self),
+            Some(index) => {
+                let node = self.node_at(index);
+                CursorMut::new(self, Some(node), index).split_after()
+            }
+        }
+    }
+
+    /// Cursor on the head able to edit the list after it, or on the ghost position if the list
+    /// is empty
+    ///
+    /// ```
+    /// use data_structure::linear::SinglyLinkedList;
+    ///
+    /// let mut list = SinglyLinkedList::from([1, 2, 4]);
+    /// let mut cursor = list.cursor_front_mut();
+    /// cursor.move_next();
+    /// cursor.insert_after(3);
+    /// assert_eq!(cursor.remove_next(), Some(3));
+    /// assert_eq!(cursor.peek_next(), Some(&mut 4));
+    /// let back = cursor.split_after();
+    /// assert_eq!(list.to_string(), "[1, 2]");
+    /// assert_eq!(back.to_string(), "[4]");
+    /// ```
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let head = self.head;
+        CursorMut::new(self, head, 0)
+    }
+
+    /// Keep only the elements for which `keep` returns `true`, in one pass from the head
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F) {
+        self.retain_mut(|item| keep(item))
+    }
+
+    /// Keep only the elements for which `keep` returns `true`, in one pass from the head, with
+    /// mutable access to them
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut keep: F) {
+        self.extract_if(|item| !keep(item)).for_each(drop);
+    }
+
+    /// Iterate over the elements for which `filter` returns `true`, from the head to the tail,
+    /// removing them from the list as they are yielded
+    ///
+    /// ```
+    /// use data_structure::linear::SinglyLinkedList;
+    ///
+    /// let mut list = SinglyLinkedList::from([1, 2, 3, 4, 5]);
+    /// let even: Vec<i32> = list.extract_if(|item| *item % 2 == 0).collect();
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(list.to_string(), "[1, 3, 5]");
+    /// ```
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> ExtractIf<'_, T, F> {
+        // From the ghost position, the first element tested is the head
+        ExtractIf::new(CursorMut::new(self, None, 0), filter)
+    }
+
+    /// Remove the consecutive elements for which `same_bucket` returns `true`, keeping the first
+    /// of each run. `same_bucket` is passed the element to remove or keep, then the one kept
+    /// before it.
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let mut cursor = self.cursor_front_mut();
+        while let Some((current, next)) = cursor.current_and_next() {
+            if same_bucket(next, current) {
+                cursor.remove_next();
+            } else {
+                cursor.move_next();
+            }
+        }
+    }
+
+    /// Node at position `index` from the head, that must be lower than the length
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        debug_assert!(index < self.length);
+        let mut node = self.head.unwrap();
+        for _ in 0..index {
+            node = unsafe { (*node.as_ptr()).next.unwrap() };
+        }
+        node
+    }
+}
+
+impl<T: PartialEq> SinglyLinkedList<T> {
+    pub fn find(&self, value: &T) -> Option<usize> {
+        self.iter().position(|item| item == value)
+    }
+}
+
+impl<T> FromIterator<T> for SinglyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SinglyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for SinglyLinkedList<T> {
+    /// Push the items after the tail, in order
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for SinglyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T> From<Vec<T>> for SinglyLinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SinglyLinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T> From<SinglyLinkedList<T>> for Vec<T> {
+    /// Elements go from the head to the tail
+    fn from(list: SinglyLinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> Default for SinglyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for SinglyLinkedList<T> {
+    /// Elements from the head to the tail
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for SinglyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SinglyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SinglyLinkedList<T> {}
+
+impl<T: PartialOrd> PartialOrd for SinglyLinkedList<T> {
+    /// Lexicographic comparison of the elements from the head to the tail
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for SinglyLinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for SinglyLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T> IntoIterator for SinglyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consume the list, yielding its elements from the head to the tail
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SinglyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SinglyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
 }
 
 impl<T> Index<usize> for SinglyLinkedList<T> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
-        if index >= self.length {
-            panic!("Index {} out of bounds, list length is {}", index, self.length);
-        }
-        let mut node_box = self.head.as_ref().unwrap();
-        for _ in 0..index {
-            node_box = node_box.next.as_ref().unwrap();
-        }
-        &node_box.value
+        self.get_ref(index).unwrap_or_else(|error| error.raise())
     }
 }
 
 impl<T> IndexMut<usize> for SinglyLinkedList<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index >= self.length {
-            panic!("Index {} out of bounds, list length is {}", index, self.length);
-        }
-        let mut node_box = self.head.as_mut().unwrap();
-        for _ in 0..index {
-            node_box = node_box.next.as_mut().unwrap();
-        }
-        &mut node_box.value
+        self.get_mut(index).unwrap_or_else(|error| error.raise())
     }
 }
 
 impl<T: Display> Display for SinglyLinkedList<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "[")?;
-        let mut node_box = self.head.as_ref().unwrap();
-        for i in 0..self.length {
-            write!(f, "{}", node_box.value)?;
-            if i < self.length - 1 {
+        for (i, item) in self.iter().enumerate() {
+            if i != 0 {
                 write!(f, ", ")?;
             }
+            write!(f, "{}", item)?;
         }
         write!(f, "]")
     }
 }
+
+impl<T> Drop for SinglyLinkedList<T> {
+    fn drop(&mut self) {
+        // One node at a time: dropping them as a chain would recurse once per node
+        while self.pop_front().is_some() {}
+    }
+}
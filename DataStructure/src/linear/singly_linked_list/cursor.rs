@@ -0,0 +1,131 @@
+//! Forward cursor over a [`SinglyLinkedList`], editing the list after the element it points at.
+//!
+//! Besides the elements, the cursor can rest on a "ghost" position after the tail, that is also
+//! before the head: moving next from the tail reaches it, and moving next from there reaches the
+//! head. On the ghost position, `current` returns `None` and the edits after it apply at the head.
+
+use std::ptr::NonNull;
+
+use super::node::Node;
+use super::SinglyLinkedList;
+
+/// Cursor able to edit the list in O(1) after its position, returned by
+/// [`SinglyLinkedList::cursor_front_mut`].
+pub struct CursorMut<'a, T> {
+    list: &'a mut SinglyLinkedList<T>,
+    // Node the cursor points at, `None` for the ghost position, and its position from the head
+    // (the length of the list for the ghost position)
+    current: Option<NonNull<Node<T>>>,
+    index: usize,
+}
+
+// Same as the reference to the list the cursor stands for
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: Sync> Sync for CursorMut<'_, T> {}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Cursor on node `current` of `list`, at position `index`
+    pub(super) fn new(
+        list: &'a mut SinglyLinkedList<T>,
+        current: Option<NonNull<Node<T>>>,
+        index: usize,
+    ) -> Self {
+        let index = current.map_or(list.length, |_| index);
+        CursorMut { list, current, index }
+    }
+
+    /// Position of the current element from the head, `None` on the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Move to the next element, from the ghost position to the head, and from the tail to the
+    /// ghost position
+    pub fn move_next(&mut self) {
+        match self.current {
+            None => (self.current, self.index) = (self.list.head, 0),
+            Some(_) => (self.current, self.index) = (self.next_node(), self.index + 1),
+        }
+    }
+
+    /// Element the cursor points at, `None` on the ghost position
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Element `move_next` would reach
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.next_node().map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Insert `value` after the current element, or at the head on the ghost position. The
+    /// cursor stays on the same element.
+    pub fn insert_after(&mut self, value: T) {
+        let Some(current) = self.current else {
+            self.list.push_front(value);
+            self.index += 1;
+            return;
+        };
+        let mut new_node = Box::new(Node::new(value));
+        unsafe {
+            new_node.next = (*current.as_ptr()).next;
+            let new_node = NonNull::from(Box::leak(new_node));
+            (*current.as_ptr()).next = Some(new_node);
+            if self.list.tail == Some(current) {
+                self.list.tail = Some(new_node);
+            }
+        }
+        self.list.length += 1;
+    }
+
+    /// Remove the element after the current one, or the head on the ghost position. The cursor
+    /// stays on the same element.
+    pub fn remove_next(&mut self) -> Option<T> {
+        let Some(current) = self.current else {
+            let head = self.list.pop_front()?;
+            self.index -= 1;
+            return Some(head);
+        };
+        unsafe {
+            let next = Box::from_raw((*current.as_ptr()).next?.as_ptr());
+            (*current.as_ptr()).next = next.next;
+            if next.next.is_none() {
+                self.list.tail = Some(current);
+            }
+            self.list.length -= 1;
+            Some(next.value)
+        }
+    }
+
+    /// Move the elements after the current one to a new list, or all of them on the ghost
+    /// position. The current element becomes the tail.
+    pub fn split_after(&mut self) -> SinglyLinkedList<T> {
+        let Some(current) = self.current else {
+            self.index = 0;
+            return std::mem::take(self.list);
+        };
+        let mut back = SinglyLinkedList::new();
+        back.head = unsafe { (*current.as_ptr()).next.take() };
+        if back.head.is_some() {
+            back.tail = self.list.tail.replace(current);
+            back.length = self.list.length - (self.index + 1);
+            self.list.length = self.index + 1;
+        }
+        back
+    }
+
+    /// Current element and the next one, if the cursor is on an element before the tail
+    pub(super) fn current_and_next(&mut self) -> Option<(&mut T, &mut T)> {
+        let (current, next) = (self.current?, self.next_node()?);
+        // Distinct nodes, so the values never alias
+        unsafe { Some((&mut (*current.as_ptr()).value, &mut (*next.as_ptr()).value)) }
+    }
+
+    /// Node after the current one, the head after the ghost position
+    fn next_node(&self) -> Option<NonNull<Node<T>>> {
+        match self.current {
+            None => self.list.head,
+            Some(node) => unsafe { (*node.as_ptr()).next },
+        }
+    }
+}
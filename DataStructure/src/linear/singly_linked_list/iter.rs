@@ -0,0 +1,207 @@
+//! Iterators over a [`SinglyLinkedList`], yielding elements from the head to the tail.
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use super::node::Node;
+use super::{CursorMut, SinglyLinkedList};
+
+/// Borrowing iterator returned by [`SinglyLinkedList::iter`].
+pub struct Iter<'a, T> {
+    // Next node to yield, and number of elements left
+    next: Option<NonNull<Node<T>>>,
+    length: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+/// Mutably borrowing iterator returned by [`SinglyLinkedList::iter_mut`].
+pub struct IterMut<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    length: usize,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+/// Owning iterator returned by [`SinglyLinkedList::into_iter`].
+pub struct IntoIter<T> {
+    list: SinglyLinkedList<T>,
+}
+
+/// Iterator over a range of elements removed from a list, returned by
+/// [`SinglyLinkedList::drain`].
+///
+/// The range is already unlinked from the list: elements not yielded are dropped with the
+/// iterator.
+pub struct Drain<'a, T> {
+    // Nodes of the range not yielded yet, that the iterator owns
+    next: Option<NonNull<Node<T>>>,
+    length: usize,
+    _marker: PhantomData<&'a mut SinglyLinkedList<T>>,
+}
+
+/// Iterator removing the elements matching a filter, returned by
+/// [`SinglyLinkedList::extract_if`].
+///
+/// Elements are tested as the iterator goes: the ones after the last element yielded stay in the
+/// list when it is dropped.
+pub struct ExtractIf<'a, T, F> {
+    // Cursor on the last element kept
+    cursor: CursorMut<'a, T>,
+    filter: F,
+}
+
+// Same as the references the iterators stand for
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+unsafe impl<T: Send> Send for Drain<'_, T> {}
+unsafe impl<T: Sync> Sync for Drain<'_, T> {}
+
+impl<T> Iter<'_, T> {
+    /// Iterate over the `length` elements starting at node `head`
+    pub(super) fn new(head: Option<NonNull<Node<T>>>, length: usize) -> Self {
+        Iter { next: head, length, _marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        // Nodes live as long as the list borrowed for 'a
+        let node = unsafe { &*self.next?.as_ptr() };
+        self.next = node.next;
+        self.length -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { next: self.next, length: self.length, _marker: PhantomData }
+    }
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Iterate over the `length` elements starting at node `head`. The list is borrowed
+    /// mutably for `'a` by the caller.
+    pub(super) fn new(head: Option<NonNull<Node<T>>>, length: usize) -> Self {
+        IterMut { next: head, length, _marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        // Each node is yielded once, so the mutable references never alias
+        let node = unsafe { &mut *self.next?.as_ptr() };
+        self.next = node.next;
+        self.length -= 1;
+        Some(&mut node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> IntoIter<T> {
+    pub(super) fn new(list: SinglyLinkedList<T>) -> Self {
+        IntoIter { list }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.length, Some(self.list.length))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drain<'_, T> {
+    /// Take ownership of the `length` nodes starting at `head`, unlinked from their list
+    pub(super) fn new(head: Option<NonNull<Node<T>>>, length: usize) -> Self {
+        Drain { next: head, length, _marker: PhantomData }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+        let node = unsafe { Box::from_raw(self.next?.as_ptr()) };
+        self.next = node.next;
+        self.length -= 1;
+        Some(node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        // Free the nodes not yielded
+        self.for_each(drop);
+    }
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> ExtractIf<'a, T, F> {
+    /// Test the elements after the position of `cursor` with `filter`
+    pub(super) fn new(cursor: CursorMut<'a, T>, filter: F) -> Self {
+        ExtractIf { cursor, filter }
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(item) = self.cursor.peek_next() {
+            if (self.filter)(item) {
+                return self.cursor.remove_next();
+            }
+            self.cursor.move_next();
+        }
+        None
+    }
+}
+
+impl<T, F: FnMut(&mut T) -> bool> FusedIterator for ExtractIf<'_, T, F> {}
@@ -1,7 +1,9 @@
+use std::ptr::NonNull;
+
 #[derive(Debug)]
 pub struct Node<T> {
     pub value: T,
-    pub next: Option<Box<Node<T>>>,
+    pub next: Option<NonNull<Node<T>>>,
 }
 
 impl<T> Node<T> {
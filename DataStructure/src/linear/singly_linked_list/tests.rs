@@ -0,0 +1,338 @@
+use super::SinglyLinkedList;
+use crate::error::Error;
+use std::collections::HashSet;
+
+const LIST_ARRAY: [i32; 6] = [4, -2, 7, 0, 11, -9];
+
+fn create_list() -> SinglyLinkedList<i32> {
+    let mut list = SinglyLinkedList::new();
+    for item in LIST_ARRAY {
+        list.push_back(item);
+    }
+    list
+}
+
+#[test]
+fn test_push_pop() {
+    let mut list = SinglyLinkedList::new();
+    assert!(list.is_empty());
+    list.push_back(1);
+    list.push_front(0);
+    list.push_back(2);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.pop_front(), Some(0));
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(list.pop_front(), None);
+}
+
+#[test]
+fn test_empty_errors() {
+    fn pop_ends(list: &mut SinglyLinkedList<i32>) -> Result<(i32, i32), Error> {
+        Ok((list.try_pop_front()?, list.try_pop_back()?))
+    }
+    let mut list = create_list();
+    assert_eq!(pop_ends(&mut list), Ok((4, -9)));
+    let mut list = SinglyLinkedList::from([1]);
+    assert_eq!(pop_ends(&mut list), Err(Error::Empty));
+    assert!(list.is_empty());
+    assert_eq!(list.try_pop_back(), Err(Error::Empty));
+}
+
+#[test]
+fn test_get() {
+    let mut list = create_list();
+    for (i, &item) in LIST_ARRAY.iter().enumerate() {
+        assert_eq!(list.get(i), Ok(item));
+        assert_eq!(list.get_ref(i), Ok(&item));
+        assert_eq!(list[i], item);
+    }
+    *list.get_mut(2).unwrap() = 70;
+    assert_eq!(list[2], 70);
+    let len = LIST_ARRAY.len();
+    assert_eq!(list.get_ref(len), Err(Error::IndexOutOfBounds { index: len, len }));
+    assert_eq!(list.get_mut(len + 3), Err(Error::IndexOutOfBounds { index: len + 3, len }));
+}
+
+#[test]
+fn test_remove() {
+    let mut list = create_list();
+    assert_eq!(list.remove(2), Ok(LIST_ARRAY[2]));
+    assert_eq!(list.remove(0), Ok(LIST_ARRAY[0]));
+    assert_eq!(list.remove(list.len() - 1), Ok(LIST_ARRAY[5]));
+    assert_eq!(list.len(), LIST_ARRAY.len() - 3);
+    assert_eq!(list.remove(3), Err(Error::IndexOutOfBounds { index: 3, len: 3 }));
+    assert_eq!(list.find(&0), Some(1));
+    // Removing the only element empties the list, tail included
+    let mut list = SinglyLinkedList::from([1]);
+    assert_eq!(list.remove(0), Ok(1));
+    list.push_back(2);
+    assert_eq!(list.to_string(), "[2]");
+}
+
+#[test]
+#[should_panic(expected = "index 6 out of bounds (length is 6)")]
+fn test_index_out_of_bounds() {
+    let list = create_list();
+    let _ = list[LIST_ARRAY.len()];
+}
+
+#[test]
+fn test_conversions() {
+    let list: SinglyLinkedList<i32> = LIST_ARRAY.into_iter().collect();
+    assert_eq!(Vec::from(list), LIST_ARRAY);
+    let mut list = SinglyLinkedList::from(LIST_ARRAY.to_vec());
+    list.extend([1, 2]);
+    list.extend(&[3]);
+    assert_eq!(list.len(), LIST_ARRAY.len() + 3);
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(Vec::from(list), LIST_ARRAY.into_iter().chain([1]).collect::<Vec<i32>>());
+    assert_eq!(Vec::from(SinglyLinkedList::from([1, 2, 3])), [1, 2, 3]);
+    assert_eq!(Vec::from(SinglyLinkedList::<i32>::new()), []);
+}
+
+#[test]
+fn test_standard_traits() {
+    let list = create_list();
+    assert_eq!(format!("{:?}", list), "[4, -2, 7, 0, 11, -9]");
+    let mut clone = list.clone();
+    assert_eq!(clone, list);
+    clone.push_back(1);
+    assert!(clone > list);
+    clone.pop_front();
+    assert!(clone < list);
+    assert_eq!(SinglyLinkedList::<i32>::default(), SinglyLinkedList::new());
+    let set: HashSet<SinglyLinkedList<i32>> = [list.clone(), create_list(), clone].into();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&list));
+    let handle = std::thread::spawn(move || list.len());
+    assert_eq!(handle.join().unwrap(), LIST_ARRAY.len());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(create_list().to_string(), "[4, -2, 7, 0, 11, -9]");
+    assert_eq!(SinglyLinkedList::<i32>::new().to_string(), "[]");
+}
+
+#[test]
+fn test_iter() {
+    let mut list = create_list();
+    assert!(list.iter().eq(LIST_ARRAY.iter()));
+    assert_eq!(list.iter().len(), LIST_ARRAY.len());
+    for item in &mut list {
+        *item *= 10;
+    }
+    let multiplied: Vec<i32> = (&list).into_iter().copied().collect();
+    assert_eq!(multiplied, LIST_ARRAY.map(|item| item * 10));
+    let mut iter = list.iter_mut();
+    *iter.nth(5).unwrap() = 1;
+    assert_eq!(iter.next(), None);
+    assert_eq!(list.pop_back(), Some(1));
+    // The tail follows pops from both ends
+    list.push_back(2);
+    assert_eq!(list.iter().last(), Some(&2));
+    assert_eq!(SinglyLinkedList::<i32>::new().iter().next(), None);
+}
+
+#[test]
+fn test_into_iter() {
+    let mut iter = create_list().into_iter();
+    assert_eq!(iter.next(), Some(LIST_ARRAY[0]));
+    assert_eq!(iter.len(), LIST_ARRAY.len() - 1);
+    assert!(iter.eq(LIST_ARRAY[1..].iter().copied()));
+}
+
+#[test]
+fn test_drain() {
+    let mut list = create_list();
+    assert_eq!(list.drain(1..3).collect::<Vec<i32>>(), [-2, 7]);
+    assert_eq!(list.to_string(), "[4, 0, 11, -9]");
+    // Up to the tail: the tail moves back
+    let mut drain = list.drain(2..);
+    assert_eq!(drain.len(), 2);
+    assert_eq!(drain.next(), Some(11));
+    drop(drain);
+    list.push_back(5);
+    assert_eq!(list.to_string(), "[4, 0, 5]");
+    assert_eq!(list.drain(..0).next(), None);
+    assert_eq!(list.drain(..).collect::<Vec<i32>>(), [4, 0, 5]);
+    assert!(list.is_empty());
+    list.push_back(1);
+    list.push_front(0);
+    assert_eq!(list.to_string(), "[0, 1]");
+}
+
+#[test]
+#[should_panic(expected = "index 7 out of bounds (length is 6)")]
+fn test_drain_out_of_bounds() {
+    create_list().drain(2..7);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_long_list_drop() {
+    // Nodes are dropped one at a time, not recursively
+    let list: SinglyLinkedList<i32> = (0..1_000_000).collect();
+    drop(list);
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut list = create_list();
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 4)));
+    cursor.insert_after(1);
+    assert_eq!(cursor.peek_next(), Some(&mut 1));
+    cursor.move_next();
+    assert_eq!(cursor.remove_next(), Some(-2));
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&mut 1)));
+    for _ in 0..4 {
+        cursor.move_next();
+    }
+    // Edits after the tail move it
+    assert_eq!((cursor.index(), cursor.peek_next()), (Some(5), None));
+    assert_eq!(cursor.remove_next(), None);
+    cursor.insert_after(3);
+    assert_eq!(cursor.remove_next(), Some(3));
+    cursor.insert_after(5);
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    // On the ghost position, edits apply at the head
+    assert_eq!(cursor.peek_next(), Some(&mut 4));
+    assert_eq!(cursor.remove_next(), Some(4));
+    cursor.insert_after(2);
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 2)));
+    list.push_back(6);
+    assert_eq!(list.to_string(), "[2, 1, 7, 0, 11, -9, 5, 6]");
+
+    let mut list = SinglyLinkedList::new();
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!((cursor.index(), cursor.peek_next()), (None, None));
+    cursor.insert_after(1);
+    assert_eq!(cursor.remove_next(), Some(1));
+    assert_eq!(cursor.remove_next(), None);
+    cursor.insert_after(0);
+    list.push_back(1);
+    assert_eq!(list.to_string(), "[0, 1]");
+}
+
+#[test]
+fn test_cursor_mut_split_after() {
+    let mut list = create_list();
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    let mut back = cursor.split_after();
+    assert_eq!(cursor.peek_next(), None);
+    assert!(cursor.split_after().is_empty());
+    back.push_back(1);
+    list.push_back(2);
+    assert_eq!((list.len(), list.to_string()), (3, "[4, -2, 2]".to_string()));
+    assert_eq!((back.len(), back.to_string()), (5, "[7, 0, 11, -9, 1]".to_string()));
+
+    // On the ghost position, the whole list is split off
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    let all = cursor.split_after();
+    assert_eq!(cursor.index(), None);
+    cursor.insert_after(0);
+    assert_eq!(all.to_string(), "[4, -2, 2]");
+    assert_eq!(list.to_string(), "[0]");
+}
+
+#[test]
+fn test_retain() {
+    let mut list = create_list();
+    list.retain(|item| *item > 0);
+    assert_eq!(list.to_string(), "[4, 7, 11]");
+    list.retain_mut(|item| {
+        *item *= 2;
+        *item != 8
+    });
+    list.push_back(1);
+    assert_eq!(list.to_string(), "[14, 22, 1]");
+    list.retain(|_| false);
+    assert!(list.is_empty());
+    list.push_back(1);
+    assert_eq!(list.to_string(), "[1]");
+}
+
+#[test]
+fn test_extract_if() {
+    let mut list = create_list();
+    let negative: Vec<i32> = list.extract_if(|item| *item < 0).collect();
+    assert_eq!(negative, [-2, -9]);
+    list.push_back(3);
+    assert_eq!(list.to_string(), "[4, 7, 0, 11, 3]");
+    // Dropping the iterator leaves the elements after the last yielded one
+    assert_eq!(list.extract_if(|item| *item % 2 == 1).next(), Some(7));
+    assert_eq!(list.to_string(), "[4, 0, 11, 3]");
+    assert_eq!(list.extract_if(|_| true).count(), 4);
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.extract_if(|_| true).next(), None);
+}
+
+#[test]
+fn test_dedup_by() {
+    let mut list = SinglyLinkedList::from([1, 1, 2, 3, 3, 3, 1, 4, 4]);
+    list.dedup_by(|item, kept| item == kept);
+    assert_eq!(list.to_string(), "[1, 2, 3, 1, 4]");
+    list.push_back(5);
+    assert_eq!(list.len(), 6);
+    // The kept element can be updated with the removed ones
+    let mut list = SinglyLinkedList::from([(0, 1), (0, 2), (1, 3), (1, 4), (0, 5)]);
+    list.dedup_by(|item, kept| {
+        let same = item.0 == kept.0;
+        if same {
+            kept.1 += item.1;
+        }
+        same
+    });
+    assert_eq!(Vec::from(list), [(0, 3), (1, 7), (0, 5)]);
+    let mut empty = SinglyLinkedList::<i32>::new();
+    empty.dedup_by(|_, _| true);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_append() {
+    let mut list = create_list();
+    let mut other = SinglyLinkedList::from([1, 2]);
+    list.append(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(list.len(), 8);
+    list.append(&mut other);
+    other.append(&mut list);
+    assert!(list.is_empty());
+    // The tails are still right
+    other.push_back(3);
+    list.push_back(0);
+    assert_eq!(other.to_string(), "[4, -2, 7, 0, 11, -9, 1, 2, 3]");
+    assert_eq!(list.to_string(), "[0]");
+}
+
+#[test]
+fn test_split_off() {
+    let mut list = create_list();
+    let mut back = list.split_off(4);
+    back.push_back(1);
+    list.push_back(2);
+    assert_eq!((list.len(), list.to_string()), (5, "[4, -2, 7, 0, 2]".to_string()));
+    assert_eq!((back.len(), back.to_string()), (3, "[11, -9, 1]".to_string()));
+    assert!(back.split_off(3).is_empty());
+    let all = back.split_off(0);
+    assert!(back.is_empty());
+    assert_eq!(all.to_string(), "[11, -9, 1]");
+}
+
+#[test]
+#[should_panic(expected = "index 7 out of bounds (length is 6)")]
+fn test_split_off_out_of_bounds() {
+    create_list().split_off(7);
+}
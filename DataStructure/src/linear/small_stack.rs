@@ -0,0 +1,409 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::RangeBounds;
+
+use crate::error::{Error, Result};
+use super::array_stack::ArrayStack;
+use super::growth_policy::GrowthPolicy;
+use super::range;
+use super::stack::{Stack, STACK_SIZE_INCREMENT};
+
+#[cfg(test)]
+mod tests;
+mod iter;
+
+pub use iter::IntoIter;
+pub use super::stack::{Drain, Iter, IterMut};
+
+/// [`Stack`] keeping up to `N` elements inline, that moves them to a heap buffer once it needs
+/// more room.
+///
+/// Past that point, called spilling, it behaves like a [`Stack`] growing according to its
+/// [`GrowthPolicy`]. Shrinking back to at most `N` slots moves the elements inline again.
+///
+/// ```
+/// use data_structure::linear::SmallStack;
+///
+/// let mut stack = SmallStack::<i32, 2>::new();
+/// stack.push(1);
+/// stack.push(2);
+/// assert!(!stack.spilled());
+/// stack.push(3);
+/// assert!(stack.spilled());
+/// stack.pop();
+/// stack.shrink_to_fit();
+/// assert!(!stack.spilled());
+/// ```
+pub struct SmallStack<T, const N: usize> {
+    storage: Storage<T, N>,
+}
+
+enum Storage<T, const N: usize> {
+    // The policy is kept for the heap buffer created when spilling
+    Inline(ArrayStack<T, N>, GrowthPolicy),
+    Heap(Stack<T>),
+}
+
+impl<T, const N: usize> SmallStack<T, N> {
+    pub fn new() -> SmallStack<T, N> {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty stack with room for `capacity` elements, spilled right away if it is
+    /// greater than `N`. Once spilled it grows by `STACK_SIZE_INCREMENT` slots when full.
+    pub fn with_capacity(capacity: usize) -> SmallStack<T, N> {
+        Self::with_capacity_and_policy(capacity, GrowthPolicy::Linear(STACK_SIZE_INCREMENT))
+    }
+
+    /// Create an empty stack with room for `capacity` elements, spilled right away if it is
+    /// greater than `N`. Once spilled it grows according to `policy`.
+    pub fn with_capacity_and_policy(capacity: usize, policy: GrowthPolicy) -> SmallStack<T, N> {
+        let storage = if capacity <= N {
+            Storage::Inline(ArrayStack::new(), policy)
+        } else {
+            Storage::Heap(Stack::with_capacity_and_policy(capacity, policy))
+        };
+        SmallStack { storage }
+    }
+
+    /// Whether the elements have been moved to the heap
+    pub fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    pub fn length(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(array, _) => array.length(),
+            Storage::Heap(stack) => stack.length(),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+    pub fn buffer_size(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(array, _) => array.buffer_size(),
+            Storage::Heap(stack) => stack.buffer_size(),
+        }
+    }
+    pub fn buffer_is_full(&self) -> bool {
+        self.buffer_size() == self.length()
+    }
+    pub fn growth_policy(&self) -> &GrowthPolicy {
+        match &self.storage {
+            Storage::Inline(_, policy) => policy,
+            Storage::Heap(stack) => stack.growth_policy(),
+        }
+    }
+
+    /// Make room for at least `additional` more elements like [`Stack::reserve`] does, spilling
+    /// if they do not fit inline.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Make room for exactly `additional` more elements like [`Stack::reserve_exact`] does,
+    /// spilling if they do not fit inline.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Fallible [`reserve`](Self::reserve): on error the stack is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        match &mut self.storage {
+            Storage::Heap(stack) => stack.try_reserve(additional),
+            Storage::Inline(array, policy) => {
+                let required =
+                    array.length().checked_add(additional).ok_or(Error::CapacityExceeded)?;
+                if required <= N {
+                    return Ok(());
+                }
+                // Grow like a full heap buffer of N slots would
+                let capacity = policy.next_capacity(N).map_or(required, |c| c.max(required));
+                self.try_spill(capacity)
+            }
+        }
+    }
+
+    /// Fallible [`reserve_exact`](Self::reserve_exact): on error the stack is left unchanged.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<()> {
+        match &mut self.storage {
+            Storage::Heap(stack) => stack.try_reserve_exact(additional),
+            Storage::Inline(array, _) => {
+                let required =
+                    array.length().checked_add(additional).ok_or(Error::CapacityExceeded)?;
+                if required <= N {
+                    return Ok(());
+                }
+                self.try_spill(required)
+            }
+        }
+    }
+
+    /// Move the inline elements to a heap buffer of `capacity` slots
+    fn try_spill(&mut self, capacity: usize) -> Result<()> {
+        let Storage::Inline(array, policy) = &mut self.storage else {
+            return Ok(());
+        };
+        let mut stack = Stack::with_capacity_and_policy(0, policy.clone());
+        stack.try_reserve_exact(capacity)?;
+        array.move_into(&mut stack);
+        self.storage = Storage::Heap(stack);
+        Ok(())
+    }
+
+    /// Release the buffer slots beyond `min_capacity`, keeping at least room for the elements.
+    /// A spilled stack moves its elements back inline if both fit in `N` slots.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let Storage::Heap(stack) = &mut self.storage else {
+            return;
+        };
+        if min_capacity.max(stack.length()) <= N {
+            let mut array = ArrayStack::new();
+            array.move_from(stack);
+            let policy = stack.growth_policy().clone();
+            self.storage = Storage::Inline(array, policy);
+        } else {
+            stack.shrink_to(min_capacity);
+        }
+    }
+
+    /// Release every unused buffer slot, moving the elements back inline if they fit
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    pub fn push(&mut self, item: T) {
+        if let Storage::Inline(array, _) = &self.storage {
+            if array.buffer_is_full() {
+                self.reserve(1);
+            }
+        }
+        match &mut self.storage {
+            // Never full here
+            Storage::Inline(array, _) => {
+                let _ = array.push(item);
+            }
+            Storage::Heap(stack) => stack.push(item),
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline(array, _) => array.pop(),
+            Storage::Heap(stack) => stack.pop(),
+        }
+    }
+    pub fn top(&mut self) -> Option<&T> {
+        self.as_slice().last()
+    }
+    pub fn top_mut(&mut self) -> Option<&mut T> {
+        match &mut self.storage {
+            Storage::Inline(array, _) => array.top_mut(),
+            Storage::Heap(stack) => stack.top_mut(),
+        }
+    }
+
+    /// [`pop`](Self::pop) failing with [`Error::Empty`] on an empty stack
+    pub fn try_pop(&mut self) -> Result<T> {
+        self.pop().ok_or(Error::Empty)
+    }
+
+    /// [`top`](Self::top) failing with [`Error::Empty`] on an empty stack
+    pub fn get_top(&self) -> Result<&T> {
+        self.as_slice().last().ok_or(Error::Empty)
+    }
+
+    /// [`top_mut`](Self::top_mut) failing with [`Error::Empty`] on an empty stack
+    pub fn get_top_mut(&mut self) -> Result<&mut T> {
+        self.top_mut().ok_or(Error::Empty)
+    }
+
+    /// Iterate over the elements from the top to the bottom of the stack
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.as_slice())
+    }
+
+    /// Iterate mutably over the elements from the top to the bottom of the stack
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let items = match &mut self.storage {
+            Storage::Inline(array, _) => array.as_mut_slice(),
+            Storage::Heap(stack) => stack.as_mut_slice(),
+        };
+        IterMut::new(items)
+    }
+
+    /// Remove the elements in `range` and iterate over them from the top to the bottom, like
+    /// [`Stack::drain`]. The stack stays inline or spilled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range ends after the length or starts after its end.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = range::bounds(range, self.length());
+        // Positions from the top to buffer indexes from the bottom
+        let (start, end) = (self.length() - range.end, self.length() - range.start);
+        let (bottom, length) = self.raw_parts_mut();
+        unsafe { Drain::new(bottom, length, start, end) }
+    }
+
+    /// Elements from the bottom to the top of the stack
+    fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline(array, _) => array.as_slice(),
+            Storage::Heap(stack) => stack.as_slice(),
+        }
+    }
+
+    /// Bottom slot and length of the inline or heap storage
+    fn raw_parts_mut(&mut self) -> (*mut T, &mut usize) {
+        match &mut self.storage {
+            Storage::Inline(array, _) => array.raw_parts_mut(),
+            Storage::Heap(stack) => stack.raw_parts_mut(),
+        }
+    }
+}
+
+impl<T: Display, const N: usize> Display for SmallStack<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.storage {
+            Storage::Inline(array, _) => array.fmt(f),
+            Storage::Heap(stack) => stack.fmt(f),
+        }
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for SmallStack<T, N> {
+    /// Elements from the top to the bottom
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.as_slice().iter().rev()).finish()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SmallStack<T, N> {
+    fn clone(&self) -> Self {
+        let storage = match &self.storage {
+            Storage::Inline(array, policy) => Storage::Inline(array.clone(), policy.clone()),
+            Storage::Heap(stack) => Storage::Heap(stack.clone()),
+        };
+        SmallStack { storage }
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallStack<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallStack<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for SmallStack<T, N> {
+    /// Lexicographic comparison of the elements from the top to the bottom
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().iter().rev().partial_cmp(other.as_slice().iter().rev())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for SmallStack<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().iter().rev().cmp(other.as_slice().iter().rev())
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for SmallStack<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> Default for SmallStack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallStack<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// Consume the stack, yielding its elements from the top to the bottom
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallStack<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallStack<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallStack<T, N> {
+    /// Push the items in order: the last one ends up on top
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = SmallStack::new();
+        stack.extend(iter);
+        stack
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallStack<T, N> {
+    /// Push the items in order: the last one ends up on top
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for SmallStack<T, N> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T, const N: usize> From<Vec<T>> for SmallStack<T, N> {
+    /// Take over the vector buffer without copying, like [`Stack`] does: the stack starts
+    /// spilled, and its last element is the top of the stack
+    fn from(vec: Vec<T>) -> Self {
+        SmallStack { storage: Storage::Heap(Stack::from(vec)) }
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<[T; M]> for SmallStack<T, N> {
+    /// Push the items in order: the last one ends up on top
+    fn from(array: [T; M]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<SmallStack<T, N>> for Vec<T> {
+    /// Elements from the bottom to the top, like [`Stack`]. A spilled stack hands its buffer
+    /// over without copying.
+    fn from(stack: SmallStack<T, N>) -> Self {
+        match stack.storage {
+            Storage::Inline(mut array, _) => {
+                let mut stack = Stack::with_capacity(array.length());
+                array.move_into(&mut stack);
+                Vec::from(stack)
+            }
+            Storage::Heap(stack) => Vec::from(stack),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Owning iterator over a [`SmallStack`], yielding elements from the top to the bottom. The
+//! borrowing and draining iterators are the ones of [`Stack`](crate::linear::Stack).
+
+use std::iter::FusedIterator;
+use std::ptr;
+
+use super::SmallStack;
+
+/// Owning iterator returned by [`SmallStack::into_iter`].
+pub struct IntoIter<T, const N: usize> {
+    // Elements go on living in the stack, inline or spilled: the ones up to its length that are
+    // at `start` or above are not yielded yet
+    stack: SmallStack<T, N>,
+    start: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    pub(super) fn new(stack: SmallStack<T, N>) -> Self {
+        IntoIter { stack, start: 0 }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (bottom, length) = self.stack.raw_parts_mut();
+        if *length == self.start {
+            return None;
+        }
+        *length -= 1;
+        Some(unsafe { ptr::read(bottom.add(*length)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.stack.length() - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (bottom, length) = self.stack.raw_parts_mut();
+        if *length == self.start {
+            return None;
+        }
+        self.start += 1;
+        Some(unsafe { ptr::read(bottom.add(self.start - 1)) })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // Empty the stack before dropping the elements not yielded: the ones below `start` have
+        // been moved out already
+        let (bottom, length) = self.stack.raw_parts_mut();
+        let remaining = *length - self.start;
+        let remaining = ptr::slice_from_raw_parts_mut(unsafe { bottom.add(self.start) }, remaining);
+        *length = 0;
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
@@ -0,0 +1,277 @@
+use super::SmallStack;
+use crate::error::Error;
+use crate::linear::stack::STACK_SIZE_INCREMENT;
+use crate::linear::GrowthPolicy;
+use crate::linear::test_utils::assert_clone_eq_hash;
+
+const STACK_ARRAY: [i32; 9] = [0, -1, 2, -3, 4, -5, 6, -7, 8];
+const STACK_DISPLAY: &str = "Top -> [8, -7, 6, -5, 4, -3, 2, -1, 0]";
+
+fn create_stack<const N: usize>() -> SmallStack<i32, N> {
+    let mut stack = SmallStack::new();
+    for &item in STACK_ARRAY.iter() {
+        stack.push(item);
+    }
+    stack
+}
+
+#[test]
+fn test_new() {
+    let stack = SmallStack::<i32, 4>::new();
+    assert!(stack.is_empty());
+    assert!(!stack.spilled());
+    assert_eq!(stack.buffer_size(), 4);
+    assert!(matches!(stack.growth_policy(), GrowthPolicy::Linear(STACK_SIZE_INCREMENT)));
+    assert!(SmallStack::<i32, 4>::with_capacity(5).spilled());
+    assert_eq!(SmallStack::<i32, 4>::with_capacity(5).buffer_size(), 5);
+    assert!(!SmallStack::<i32, 4>::with_capacity(4).spilled());
+}
+
+#[test]
+fn test_spill_on_push() {
+    let mut stack = SmallStack::<i32, 4>::new();
+    for (i, &item) in STACK_ARRAY.iter().enumerate() {
+        stack.push(item);
+        assert_eq!(stack.spilled(), i >= 4);
+        assert_eq!(stack.top(), Some(&item));
+    }
+    assert_eq!(stack.buffer_size(), 4 + STACK_SIZE_INCREMENT);
+    assert_eq!(stack.length(), STACK_ARRAY.len());
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    for &item in STACK_ARRAY.iter().rev() {
+        assert_eq!(stack.pop(), Some(item));
+    }
+    assert_eq!(stack.pop(), None);
+    // Popping does not move the elements back inline
+    assert!(stack.spilled());
+}
+
+#[test]
+fn test_spill_with_policy() {
+    let mut stack = SmallStack::<i32, 4>::with_capacity_and_policy(0, GrowthPolicy::Geometric(2.0));
+    for &item in STACK_ARRAY.iter() {
+        stack.push(item);
+    }
+    assert_eq!(stack.buffer_size(), 16);
+    assert!(matches!(stack.growth_policy(), GrowthPolicy::Geometric(_)));
+    stack.shrink_to_fit();
+    assert_eq!(stack.buffer_size(), STACK_ARRAY.len());
+    assert!(matches!(stack.growth_policy(), GrowthPolicy::Geometric(_)));
+}
+
+#[test]
+fn test_top_mut() {
+    let mut inline = create_stack::<16>();
+    let mut spilled = create_stack::<2>();
+    *inline.top_mut().unwrap() = 10;
+    *spilled.top_mut().unwrap() = 10;
+    assert_eq!(inline.pop(), Some(10));
+    assert_eq!(spilled.pop(), Some(10));
+    assert_eq!(SmallStack::<i32, 2>::new().top_mut(), None);
+}
+
+#[test]
+fn test_empty_errors() {
+    let mut stack = create_stack::<16>();
+    assert_eq!(stack.get_top(), Ok(&8));
+    *stack.get_top_mut().unwrap() = 9;
+    assert_eq!(stack.try_pop(), Ok(9));
+    let mut stack = SmallStack::<i32, 2>::new();
+    assert_eq!(stack.try_pop(), Err(Error::Empty));
+    assert_eq!(stack.get_top(), Err(Error::Empty));
+    assert_eq!(stack.get_top_mut(), Err(Error::Empty));
+}
+
+#[test]
+fn test_reserve() {
+    let mut stack = SmallStack::<i32, 4>::new();
+    stack.push(1);
+    stack.reserve(3);
+    assert!(!stack.spilled());
+    stack.reserve(4);
+    assert!(stack.spilled());
+    assert_eq!(stack.buffer_size(), 4 + STACK_SIZE_INCREMENT);
+    assert_eq!(stack.top(), Some(&1));
+
+    let mut exact = SmallStack::<i32, 4>::new();
+    exact.push(1);
+    exact.reserve_exact(4);
+    assert_eq!(exact.buffer_size(), 5);
+    exact.reserve_exact(10);
+    assert_eq!(exact.buffer_size(), 11);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut stack = create_stack::<16>();
+    assert_eq!(stack.try_reserve(usize::MAX), Err(Error::CapacityExceeded));
+    assert_eq!(stack.try_reserve_exact(usize::MAX / 4), Err(Error::CapacityExceeded));
+    // Left inline and untouched
+    assert!(!stack.spilled());
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    assert_eq!(stack.try_reserve(7), Ok(()));
+    assert!(!stack.spilled());
+    assert_eq!(stack.try_reserve(8), Ok(()));
+    assert!(stack.spilled());
+    assert_eq!(stack.try_reserve_exact(usize::MAX / 4), Err(Error::CapacityExceeded));
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+}
+
+#[test]
+fn test_shrink() {
+    let mut stack = create_stack::<8>();
+    assert!(stack.spilled());
+    stack.reserve_exact(20);
+    stack.shrink_to(12);
+    assert_eq!(stack.buffer_size(), 12);
+    // Still too many elements to move inline
+    stack.shrink_to_fit();
+    assert!(stack.spilled());
+    assert_eq!(stack.buffer_size(), STACK_ARRAY.len());
+    stack.pop();
+    stack.shrink_to(9);
+    assert!(stack.spilled());
+    stack.shrink_to_fit();
+    assert!(!stack.spilled());
+    assert_eq!(stack.buffer_size(), 8);
+    assert_eq!(format!("{}", stack), "Top -> [-7, 6, -5, 4, -3, 2, -1, 0]");
+    // And spills again when needed
+    stack.push(8);
+    assert!(stack.spilled());
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+}
+
+#[test]
+fn test_zero_inline_capacity() {
+    let mut stack = SmallStack::<i32, 0>::new();
+    assert!(!stack.spilled());
+    stack.push(1);
+    assert!(stack.spilled());
+    assert_eq!(stack.pop(), Some(1));
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut stack = SmallStack::<(), 2>::new();
+    for _ in 0..5 {
+        stack.push(());
+    }
+    assert!(stack.spilled());
+    assert_eq!(stack.length(), 5);
+    stack.pop();
+    stack.pop();
+    stack.pop();
+    stack.shrink_to_fit();
+    assert!(!stack.spilled());
+    assert_eq!(stack.length(), 2);
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let inline = create_stack::<16>();
+    assert!(!inline.clone().spilled());
+    let spilled = create_stack::<4>();
+    let spilled_clone = spilled.clone();
+    assert!(spilled_clone.spilled());
+    assert_eq!(format!("{}", spilled_clone), STACK_DISPLAY);
+    // Inline or spilled, equal elements make equal stacks
+    let mut other = SmallStack::<i32, 16>::with_capacity(20);
+    for &item in STACK_ARRAY.iter() {
+        other.push(item);
+    }
+    assert!(other.spilled());
+    assert_clone_eq_hash(&inline, &other, |clone| {
+        clone.pop();
+    });
+    assert!(SmallStack::<i32, 4>::default() == SmallStack::new());
+}
+
+#[test]
+fn test_debug_and_ord() {
+    // Inline and spilled stacks compare by their elements only
+    let inline = create_stack::<10>();
+    let spilled = create_stack::<2>();
+    assert_eq!(format!("{:?}", inline), "[8, -7, 6, -5, 4, -3, 2, -1, 0]");
+    assert_eq!(format!("{:?}", spilled), format!("{:?}", inline));
+    let mut other = create_stack::<10>();
+    assert_eq!(other.cmp(&inline), std::cmp::Ordering::Equal);
+    other.pop();
+    assert!(other < inline);
+    other.push(9);
+    assert!(other > inline);
+}
+
+#[test]
+fn test_iter() {
+    let mut inline = create_stack::<16>();
+    let mut spilled = create_stack::<4>();
+    let top_to_bottom: Vec<i32> = STACK_ARRAY.iter().rev().copied().collect();
+    assert!(inline.iter().eq(top_to_bottom.iter()));
+    assert!(spilled.iter().eq(top_to_bottom.iter()));
+    assert_eq!(spilled.iter().len(), STACK_ARRAY.len());
+    for item in &mut inline {
+        *item *= 10;
+    }
+    for item in spilled.iter_mut().rev().take(1) {
+        *item += 1;
+    }
+    assert!((&inline).into_iter().copied().eq(top_to_bottom.iter().map(|item| item * 10)));
+    assert_eq!(spilled.iter().next_back(), Some(&1));
+}
+
+#[test]
+fn test_into_iter() {
+    assert!(create_stack::<16>().into_iter().eq(STACK_ARRAY.iter().rev().copied()));
+    assert!(create_stack::<4>().into_iter().eq(STACK_ARRAY.iter().rev().copied()));
+    let mut iter = create_stack::<16>().into_iter();
+    assert_eq!(iter.next(), Some(8));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.len(), STACK_ARRAY.len() - 2);
+    assert_eq!(iter.rev().collect::<Vec<i32>>(), [-1, 2, -3, 4, -5, 6, -7]);
+}
+
+#[test]
+fn test_drain() {
+    let mut inline = create_stack::<16>();
+    let mut spilled = create_stack::<4>();
+    // Positions count from the top, the storage stays the same
+    assert_eq!(inline.drain(2..5).collect::<Vec<i32>>(), [6, -5, 4]);
+    assert_eq!(spilled.drain(2..5).rev().collect::<Vec<i32>>(), [4, -5, 6]);
+    assert!(!inline.spilled());
+    assert!(spilled.spilled());
+    assert_eq!(inline.to_string(), "Top -> [8, -7, -3, 2, -1, 0]");
+    assert!(inline.iter().eq(spilled.iter()));
+    assert_eq!(inline.drain(..).count(), 6);
+    assert!(inline.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "index 10 out of bounds (length is 9)")]
+fn test_drain_out_of_bounds() {
+    create_stack::<16>().drain(5..10);
+}
+
+#[test]
+fn test_from_iter_and_extend() {
+    let stack: SmallStack<i32, 16> = STACK_ARRAY.iter().copied().collect();
+    assert!(!stack.spilled());
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    let mut stack = SmallStack::<i32, 4>::from([1, 2]);
+    stack.extend(3..20);
+    assert!(stack.spilled());
+    assert_eq!(stack.length(), 19);
+    assert_eq!(stack.top(), Some(&19));
+    stack.extend(&[20, 21]);
+    assert_eq!(stack.pop(), Some(21));
+}
+
+#[test]
+fn test_vec_conversions() {
+    let stack = SmallStack::<i32, 16>::from(Vec::from(STACK_ARRAY));
+    // The vector buffer is taken over: the stack starts spilled
+    assert!(stack.spilled());
+    assert_eq!(stack, create_stack::<16>());
+    assert_eq!(Vec::from(create_stack::<16>()), STACK_ARRAY);
+    assert_eq!(Vec::from(create_stack::<4>()), STACK_ARRAY);
+    assert_eq!(Vec::from(SmallStack::<i32, 4>::new()), []);
+}
@@ -1,51 +1,90 @@
-use crate::linear::buffer_guard::BufferGuard;
-use std::alloc::{alloc, dealloc, Layout};
-use std::fmt::{Display, Formatter};
-use std::mem::MaybeUninit;
+use crate::error::{Error, Result};
+use crate::linear::growth_policy::GrowthPolicy;
+use crate::linear::range;
+use crate::linear::raw_buffer::RawBuffer;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::RangeBounds;
 use std::ptr;
-use std::ptr::NonNull;
+use std::slice;
 
 #[cfg(test)]
 mod tests;
+mod iter;
+
+pub use iter::{Drain, IntoIter, Iter, IterMut};
 
 pub struct Stack<T> {
-    size: usize,
     length: usize,
-    buffer: NonNull<T>,
-    layout: Layout,
+    buffer: RawBuffer<T>,
+    policy: GrowthPolicy,
 }
 
 pub const STACK_START_SIZE: usize = 10;
 pub const STACK_SIZE_INCREMENT: usize = 10;
 
-impl<T: Clone> Stack<T> {
+impl<T> Stack<T> {
     pub fn new() -> Stack<T> {
-        let layout = Layout::array::<T>(STACK_START_SIZE).expect("Layout error");
-        let ptr = NonNull::new(unsafe { alloc(layout) } as *mut T).expect("Allocation failed");
+        Self::with_capacity_and_policy(STACK_START_SIZE, GrowthPolicy::Linear(STACK_SIZE_INCREMENT))
+    }
+
+    /// Create an empty stack with room for `capacity` elements, growing according to `policy`
+    /// once full. [`new`](Self::new) is equivalent to a capacity of `STACK_START_SIZE` and a
+    /// [`GrowthPolicy::Linear`] policy of `STACK_SIZE_INCREMENT` slots.
+    pub fn with_capacity_and_policy(capacity: usize, policy: GrowthPolicy) -> Stack<T> {
         Stack {
-            size: STACK_START_SIZE,
             length: 0,
-            buffer: ptr,
-            layout,
+            buffer: RawBuffer::new(capacity),
+            policy,
         }
     }
 
-    fn _increment_size(&mut self, increment: usize) {
-        let new_size = self.size + increment;
-        // Create a temporary new buffer that will be correctly dropped if something goes wrong
-        let buffer_guard = BufferGuard::<T>::new(new_size);
-        for i in 0..self.length {
-            unsafe {
-                ptr::write(
-                    buffer_guard.as_ptr().add(i),
-                    ptr::read(self.buffer.as_ptr().add(i)),
-                )
-            };
-        }
-        // Deallocate the old buffer and replace it with the new in the buffer guard
-        unsafe { dealloc(self.buffer.as_ptr() as *mut u8, self.layout) };
-        (self.buffer, self.layout) = buffer_guard.into_inner();
-        self.size = new_size;
+    /// Create an empty stack with room for `capacity` elements, growing by
+    /// `STACK_SIZE_INCREMENT` slots once full
+    pub fn with_capacity(capacity: usize) -> Stack<T> {
+        Self::with_capacity_and_policy(capacity, GrowthPolicy::Linear(STACK_SIZE_INCREMENT))
+    }
+
+    /// Make room for at least `additional` more elements. The buffer grows at least as much as
+    /// the growth policy would, so that repeated calls stay cheap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows the maximum size of an allocation, and aborts if
+    /// the allocation fails. See [`try_reserve`](Self::try_reserve) for a fallible version.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Make room for exactly `additional` more elements, ignoring the growth policy.
+    ///
+    /// # Panics
+    ///
+    /// Same as [`reserve`](Self::reserve).
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap_or_else(|error| error.raise())
+    }
+
+    /// Fallible [`reserve`](Self::reserve): on error the stack is left unchanged.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        self.buffer.try_reserve(&self.policy, 0, self.length, additional)
+    }
+
+    /// Fallible [`reserve_exact`](Self::reserve_exact): on error the stack is left unchanged.
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<()> {
+        self.buffer.try_reserve_exact(0, self.length, additional)
+    }
+
+    /// Release the buffer slots beyond `min_capacity`, keeping at least room for the elements
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.buffer.shrink_to(min_capacity, 0, self.length);
+    }
+
+    /// Release every unused buffer slot
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
     }
 
     pub fn length(&self) -> usize {
@@ -55,18 +94,22 @@ impl<T: Clone> Stack<T> {
         self.length == 0
     }
     pub fn buffer_size(&self) -> usize {
-        self.size
+        self.buffer.capacity()
     }
     pub fn buffer_is_full(&self) -> bool {
-        self.size == self.length
+        self.buffer.capacity() == self.length
+    }
+    pub fn growth_policy(&self) -> &GrowthPolicy {
+        &self.policy
     }
 
     pub fn push(&mut self, item: T) {
         if self.buffer_is_full() {
-            self._increment_size(STACK_SIZE_INCREMENT);
+            self.buffer.grow(&self.policy, 0, self.length);
         }
+        // The slot is uninitialized: write without dropping its previous content
         unsafe {
-            *self.buffer.as_ptr().add(self.length) = item;
+            ptr::write(self.buffer.ptr(self.length), item);
         }
         self.length += 1;
     }
@@ -74,46 +117,248 @@ impl<T: Clone> Stack<T> {
     pub fn pop(&mut self) -> Option<T> {
         if self.length > 0 {
             self.length -= 1;
-            return Some(unsafe { ptr::read(self.buffer.as_ptr().add(self.length)) });
+            return Some(unsafe { ptr::read(self.buffer.ptr(self.length)) });
         }
         None
     }
     pub fn top(&mut self) -> Option<&T> {
         if self.length > 0 {
-            return Some(unsafe { &*self.buffer.as_ptr().add(self.length - 1) });
+            return Some(unsafe { &*self.buffer.ptr(self.length - 1) });
         }
         None
     }
     pub fn top_mut(&mut self) -> Option<&mut T> {
         if self.length > 0 {
-            return Some(unsafe { &mut *self.buffer.as_ptr().add(self.length - 1) });
+            return Some(unsafe { &mut *self.buffer.ptr(self.length - 1) });
         }
         None
     }
+
+    /// [`pop`](Self::pop) failing with [`Error::Empty`] on an empty stack
+    pub fn try_pop(&mut self) -> Result<T> {
+        self.pop().ok_or(Error::Empty)
+    }
+
+    /// [`top`](Self::top) failing with [`Error::Empty`] on an empty stack
+    pub fn get_top(&self) -> Result<&T> {
+        if self.length == 0 {
+            return Err(Error::Empty);
+        }
+        Ok(unsafe { &*self.buffer.ptr(self.length - 1) })
+    }
+
+    /// [`top_mut`](Self::top_mut) failing with [`Error::Empty`] on an empty stack
+    pub fn get_top_mut(&mut self) -> Result<&mut T> {
+        self.top_mut().ok_or(Error::Empty)
+    }
+
+    /// Iterate over the elements from the top to the bottom of the stack
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.as_slice())
+    }
+
+    /// Iterate mutably over the elements from the top to the bottom of the stack
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.as_mut_slice())
+    }
+
+    /// Remove the elements in `range` and iterate over them from the top to the bottom. The
+    /// range counts positions from the top of the stack, like [`iter`](Self::iter): `0` is the
+    /// top element. Elements left in the range are removed when the iterator is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range ends after the length or starts after its end.
+    ///
+    /// ```
+    /// use data_structure::linear::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// for i in 0..5 {
+    ///     stack.push(i);
+    /// }
+    /// let drained: Vec<i32> = stack.drain(1..3).collect();
+    /// assert_eq!(drained, [3, 2]);
+    /// assert_eq!(stack.to_string(), "Top -> [4, 1, 0]");
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let range = range::bounds(range, self.length);
+        // Positions from the top to buffer indexes from the bottom
+        let (start, end) = (self.length - range.end, self.length - range.start);
+        let (bottom, length) = self.raw_parts_mut();
+        unsafe { Drain::new(bottom, length, start, end) }
+    }
+
+    /// Elements from the bottom to the top of the stack
+    pub(super) fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buffer.ptr(0), self.length) }
+    }
+    pub(super) fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.buffer.ptr(0), self.length) }
+    }
+
+    /// Bottom slot and length, for the iterators editing the stack in place
+    pub(super) fn raw_parts_mut(&mut self) -> (*mut T, &mut usize) {
+        (self.buffer.ptr(0), &mut self.length)
+    }
 }
 
 impl<T: std::fmt::Display> Display for Stack<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Top -> [")?;
-        for i in (0..self.length).rev() {
-            write!(f, "{}", unsafe { self.buffer.as_ptr().add(i).read() })?;
+        for (i, item) in self.iter().enumerate() {
             if i != 0 {
                 write!(f, ", ")?;
             }
+            write!(f, "{}", item)?;
         }
         write!(f, "]")
     }
 }
 
+impl<T: Debug> Debug for Stack<T> {
+    /// Elements from the top to the bottom
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone> Clone for Stack<T> {
+    fn clone(&self) -> Self {
+        let mut clone = Stack {
+            length: 0,
+            buffer: RawBuffer::new(self.buffer.capacity()),
+            policy: self.policy.clone(),
+        };
+        // Elements are counted one at a time: if a clone panics the copied ones are dropped
+        for item in self.as_slice() {
+            clone.push(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T: PartialEq> PartialEq for Stack<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq> Eq for Stack<T> {}
+
+impl<T: PartialOrd> PartialOrd for Stack<T> {
+    /// Lexicographic comparison of the elements from the top to the bottom
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for Stack<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for Stack<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consume the stack, yielding its elements from the top to the bottom
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter::new(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    /// Push the items in order: the last one ends up on top
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Stack::new();
+        stack.extend(iter);
+        stack
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    /// Push the items in order: the last one ends up on top
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T> From<Vec<T>> for Stack<T> {
+    /// Take over the vector buffer without copying: its last element is the top of the stack
+    fn from(vec: Vec<T>) -> Self {
+        Stack {
+            length: vec.len(),
+            buffer: RawBuffer::from_vec(vec),
+            policy: GrowthPolicy::Linear(STACK_SIZE_INCREMENT),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Stack<T> {
+    /// Push the items in order: the last one ends up on top
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T> From<Stack<T>> for Vec<T> {
+    /// Hand the stack buffer over without copying: elements go from the bottom to the top, the
+    /// reverse of [`Stack::iter`], so that converting back gives the same stack
+    fn from(mut stack: Stack<T>) -> Self {
+        let length = mem::replace(&mut stack.length, 0);
+        let buffer = mem::replace(&mut stack.buffer, RawBuffer::new(0));
+        buffer.into_vec(0, length)
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for Stack<T> {
     fn drop(&mut self) {
+        // Drop every element to ensure custom and deep drops are executed, the buffer array is
+        // deallocated by its own drop
         unsafe {
-            // Drop every element to ensure custom and deep drops are executed
-            for i in 0..self.length {
-                ptr::drop_in_place(self.buffer.as_ptr().add(i));
-            }
-            // Drop the buffer array
-            dealloc(self.buffer.as_ptr() as _, self.layout);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buffer.ptr(0), self.length));
         }
     }
 }
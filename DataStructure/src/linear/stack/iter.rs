@@ -0,0 +1,237 @@
+//! Iterators over a [`Stack`], yielding elements from the top to the bottom. Except for
+//! [`IntoIter`], they also iterate over a [`SmallStack`](crate::linear::SmallStack), whether its
+//! elements are inline or spilled.
+
+use std::iter::{FusedIterator, Rev};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use super::Stack;
+use crate::linear::raw_buffer::RawBuffer;
+
+/// Borrowing iterator returned by [`Stack::iter`].
+#[derive(Clone)]
+pub struct Iter<'a, T> {
+    iter: Rev<slice::Iter<'a, T>>,
+}
+
+/// Mutably borrowing iterator returned by [`Stack::iter_mut`].
+pub struct IterMut<'a, T> {
+    iter: Rev<slice::IterMut<'a, T>>,
+}
+
+/// Owning iterator returned by [`Stack::into_iter`].
+pub struct IntoIter<T> {
+    buffer: RawBuffer<T>,
+    // Buffer indexes of the elements not yielded yet: `end` is past the top one
+    start: usize,
+    end: usize,
+}
+
+/// Iterator removing a range of elements, returned by [`Stack::drain`].
+///
+/// Elements of the range not yielded are dropped with the iterator, then the elements above the
+/// range move down towards the bottom to close the gap.
+pub struct Drain<'a, T> {
+    // Bottom slot and length of the drained stack, borrowed for 'a
+    bottom: *mut T,
+    length: &'a mut usize,
+    // Buffer indexes of the elements not yielded yet: `end` is past the top one
+    start: usize,
+    end: usize,
+    // Buffer index past the top of the drained range, and number of elements above it
+    drain_end: usize,
+    tail_length: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// Same as the mutable reference to the stack the drain stands for
+unsafe impl<T: Send> Send for Drain<'_, T> {}
+unsafe impl<T: Sync> Sync for Drain<'_, T> {}
+
+impl<'a, T> Iter<'a, T> {
+    /// Iterate over `items`, from the bottom to the top of a stack
+    pub(crate) fn new(items: &'a [T]) -> Self {
+        Iter { iter: items.iter().rev() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Iterate over `items`, from the bottom to the top of a stack
+    pub(crate) fn new(items: &'a mut [T]) -> Self {
+        IterMut { iter: items.iter_mut().rev() }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> IntoIter<T> {
+    pub(super) fn new(mut stack: Stack<T>) -> Self {
+        // The stack keeps an empty buffer: its drop has nothing left to do
+        let end = mem::replace(&mut stack.length, 0);
+        let buffer = mem::replace(&mut stack.buffer, RawBuffer::new(0));
+        IntoIter { buffer, start: 0, end }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { ptr::read(self.buffer.ptr(self.end)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(unsafe { ptr::read(self.buffer.ptr(self.start - 1)) })
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        // Drop the elements not yielded, the buffer array is deallocated by its own drop
+        let remaining = self.end - self.start;
+        let remaining = ptr::slice_from_raw_parts_mut(self.buffer.ptr(self.start), remaining);
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
+
+impl<'a, T> Drain<'a, T> {
+    /// Drain the buffer indexes `start..end` of a stack of `*length` elements from `bottom`
+    ///
+    /// # Safety
+    ///
+    /// `bottom` must point at the `*length` elements of a stack mutably borrowed for 'a, along
+    /// with its length.
+    pub(crate) unsafe fn new(
+        bottom: *mut T,
+        length: &'a mut usize,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        // Until the drain is dropped the stack ends below the range: if the drain is leaked, the
+        // elements above are leaked too instead of being exposed after being moved out
+        let tail_length = *length - end;
+        *length = start;
+        Drain { bottom, length, start, end, drain_end: end, tail_length, _marker: PhantomData }
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { ptr::read(self.bottom.add(self.end)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        Some(unsafe { ptr::read(self.bottom.add(self.start - 1)) })
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        /// Closes the gap even if dropping one of the remaining elements panics
+        struct CloseGap<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+        impl<T> Drop for CloseGap<'_, '_, T> {
+            fn drop(&mut self) {
+                let drain = &mut *self.0;
+                unsafe {
+                    let tail = drain.bottom.add(drain.drain_end);
+                    ptr::copy(tail, drain.bottom.add(*drain.length), drain.tail_length);
+                }
+                *drain.length += drain.tail_length;
+            }
+        }
+
+        let guard = CloseGap(self);
+        let remaining = ptr::slice_from_raw_parts_mut(
+            unsafe { guard.0.bottom.add(guard.0.start) },
+            guard.0.end - guard.0.start,
+        );
+        guard.0.start = guard.0.end;
+        unsafe { ptr::drop_in_place(remaining) };
+    }
+}
@@ -1,7 +1,10 @@
 use super::{Stack, STACK_SIZE_INCREMENT, STACK_START_SIZE};
+use crate::error::Error;
+use crate::linear::GrowthPolicy;
+use crate::linear::test_utils::assert_clone_eq_hash;
 
 const STACK_ARRAY: [i32; 9] = [0, -1, 2, -3, 4, -5, 6, -7, 8];
-const STACK_DISPLAY: &'static str = "Top -> [0, -1, 2, -3, 4, -5, 6, -7, 8]";
+const STACK_DISPLAY: &str = "Top -> [0, -1, 2, -3, 4, -5, 6, -7, 8]";
 
 fn create_stack() -> Stack<i32> {
     let mut stack = Stack::<i32>::new();
@@ -22,10 +25,10 @@ fn test_new() {
 #[test]
 fn test_pop() {
     let mut stack = create_stack();
-    for i in 0..STACK_ARRAY.len() {
+    for &item in STACK_ARRAY.iter() {
         let popped = stack.pop();
         assert!(popped.is_some());
-        assert_eq!(popped.unwrap(), STACK_ARRAY[i]);
+        assert_eq!(popped.unwrap(), item);
     }
     assert!(stack.is_empty());
     assert!(stack.pop().is_none());
@@ -34,8 +37,8 @@ fn test_pop() {
 #[test]
 fn test_push() {
     let mut stack = Stack::<i32>::new();
-    for i in 0..STACK_ARRAY.len() {
-        stack.push(STACK_ARRAY[i]);
+    for (i, &item) in STACK_ARRAY.iter().enumerate() {
+        stack.push(item);
         assert_eq!(stack.length(), i + 1);
     }
 }
@@ -45,17 +48,32 @@ fn test_top() {
     let mut stack = Stack::<i32>::new();
     assert!(stack.top().is_none());
     assert!(stack.top_mut().is_none());
-    for i in 0..STACK_ARRAY.len() {
-        stack.push(STACK_ARRAY[i]);
+    for &item in STACK_ARRAY.iter() {
+        stack.push(item);
         let top = stack.top();
         assert!(top.is_some());
-        assert_eq!(*top.unwrap(), STACK_ARRAY[i]);
+        assert_eq!(*top.unwrap(), item);
     }
-    let mut top = stack.top_mut().unwrap();
+    let top = stack.top_mut().unwrap();
     *top = 5000;
     assert_eq!(*stack.top().unwrap(), 5000);
 }
 
+#[test]
+fn test_empty_errors() {
+    fn pop_pair(stack: &mut Stack<i32>) -> Result<(i32, i32), Error> {
+        Ok((stack.try_pop()?, stack.try_pop()?))
+    }
+    let mut stack = create_stack();
+    assert_eq!(stack.get_top(), Ok(&0));
+    *stack.get_top_mut().unwrap() = 9;
+    assert_eq!(pop_pair(&mut stack), Ok((9, -1)));
+    while stack.pop().is_some() {}
+    assert_eq!(pop_pair(&mut stack), Err(Error::Empty));
+    assert_eq!(stack.get_top(), Err(Error::Empty));
+    assert_eq!(stack.get_top_mut(), Err(Error::Empty));
+}
+
 #[test]
 fn test_increment_buffer() {
     let mut stack = create_stack();
@@ -70,7 +88,210 @@ fn test_increment_buffer() {
 
 #[test]
 fn test_display() {
-    let mut stack = create_stack();
+    let stack = create_stack();
     let s = format!("{}", stack);
     assert_eq!(s, STACK_DISPLAY.to_string());
 }
+
+#[test]
+fn test_clone_eq_hash() {
+    let stack = create_stack();
+    let mut other = Stack::with_capacity(STACK_ARRAY.len());
+    for &item in STACK_ARRAY.iter().rev() {
+        other.push(item);
+    }
+    assert_clone_eq_hash(&stack, &other, |clone| clone.push(100));
+    assert_eq!(stack.clone().buffer_size(), stack.buffer_size());
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    assert!(Stack::<i32>::new() == Stack::<i32>::new());
+}
+
+#[test]
+fn test_zero_sized_type() {
+    let mut stack = Stack::<()>::new();
+    assert_eq!(stack.buffer_size(), usize::MAX);
+    for i in 0..1000 {
+        stack.push(());
+        assert_eq!(stack.length(), i + 1);
+    }
+    assert!(!stack.buffer_is_full());
+    assert_eq!(stack.buffer_size(), usize::MAX);
+    assert_eq!(stack.top(), Some(&()));
+    assert!(stack.clone() == stack);
+    for _ in 0..1000 {
+        assert_eq!(stack.pop(), Some(()));
+    }
+    assert!(stack.pop().is_none());
+}
+
+#[test]
+fn test_with_capacity_and_policy() {
+    let mut stack = Stack::with_capacity_and_policy(0, GrowthPolicy::Geometric(2.0));
+    assert_eq!(stack.buffer_size(), 0);
+    let mut sizes = Vec::new();
+    for &item in STACK_ARRAY.iter() {
+        stack.push(item);
+        sizes.push(stack.buffer_size());
+    }
+    assert_eq!(sizes, [1, 2, 4, 4, 8, 8, 8, 8, 16]);
+    assert_eq!(format!("{}", stack), "Top -> [8, -7, 6, -5, 4, -3, 2, -1, 0]");
+    assert!(matches!(stack.growth_policy(), GrowthPolicy::Geometric(_)));
+    assert!(matches!(Stack::<i32>::new().growth_policy(), GrowthPolicy::Linear(STACK_SIZE_INCREMENT)));
+    assert!(matches!(stack.clone().growth_policy(), GrowthPolicy::Geometric(_)));
+}
+
+#[test]
+fn test_reserve_and_shrink() {
+    let mut stack = Stack::with_capacity(3);
+    assert_eq!(stack.buffer_size(), 3);
+    stack.reserve(2);
+    assert_eq!(stack.buffer_size(), 3);
+    // Growth policy of STACK_SIZE_INCREMENT slots
+    stack.reserve(4);
+    assert_eq!(stack.buffer_size(), 3 + STACK_SIZE_INCREMENT);
+    stack.reserve_exact(100);
+    assert_eq!(stack.buffer_size(), 100);
+    for &item in STACK_ARRAY.iter().rev() {
+        stack.push(item);
+    }
+    stack.shrink_to(20);
+    assert_eq!(stack.buffer_size(), 20);
+    stack.shrink_to_fit();
+    assert_eq!(stack.buffer_size(), STACK_ARRAY.len());
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    // Pushing after a shrink grows again
+    stack.push(9);
+    assert_eq!(stack.buffer_size(), STACK_ARRAY.len() + STACK_SIZE_INCREMENT);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut stack = create_stack();
+    assert_eq!(stack.try_reserve(usize::MAX), Err(Error::CapacityExceeded));
+    assert_eq!(stack.try_reserve_exact(usize::MAX / 4), Err(Error::CapacityExceeded));
+    assert_eq!(stack.buffer_size(), STACK_START_SIZE);
+    assert_eq!(stack.try_reserve(50), Ok(()));
+    assert_eq!(stack.buffer_size(), STACK_ARRAY.len() + 50);
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+}
+
+#[test]
+fn test_iter() {
+    let mut stack = create_stack();
+    let top_to_bottom: Vec<i32> = stack.iter().copied().collect();
+    assert_eq!(top_to_bottom, STACK_ARRAY);
+    assert_eq!(stack.iter().len(), STACK_ARRAY.len());
+    assert_eq!(stack.iter().next_back(), Some(&8));
+    for item in &mut stack {
+        *item *= 10;
+    }
+    for item in stack.iter_mut().rev().take(1) {
+        *item += 1;
+    }
+    let mut expected = STACK_ARRAY.map(|item| item * 10);
+    expected[STACK_ARRAY.len() - 1] += 1;
+    assert_eq!((&stack).into_iter().copied().collect::<Vec<i32>>(), expected);
+    assert_eq!(Stack::<i32>::new().iter().next(), None);
+}
+
+#[test]
+fn test_into_iter() {
+    let stack = create_stack();
+    assert_eq!(stack.into_iter().collect::<Vec<i32>>(), STACK_ARRAY);
+    let mut iter = create_stack().into_iter();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(8));
+    assert_eq!(iter.len(), STACK_ARRAY.len() - 2);
+    assert_eq!(iter.rev().collect::<Vec<i32>>(), [-7, 6, -5, 4, -3, 2, -1]);
+}
+
+#[test]
+fn test_drain() {
+    let mut stack = create_stack();
+    // Positions count from the top
+    let drained: Vec<i32> = stack.drain(2..5).collect();
+    assert_eq!(drained, [2, -3, 4]);
+    assert_eq!(format!("{}", stack), "Top -> [0, -1, -5, 6, -7, 8]");
+    let drained: Vec<i32> = stack.drain(..2).rev().collect();
+    assert_eq!(drained, [-1, 0]);
+    assert_eq!(format!("{}", stack), "Top -> [-5, 6, -7, 8]");
+    assert_eq!(stack.drain(4..).count(), 0);
+    // Elements not yielded are removed anyway
+    let mut drain = stack.drain(1..=2);
+    assert_eq!(drain.next(), Some(6));
+    drop(drain);
+    assert_eq!(format!("{}", stack), "Top -> [-5, 8]");
+    stack.push(1);
+    assert_eq!(stack.drain(..).collect::<Vec<i32>>(), [1, -5, 8]);
+    assert!(stack.is_empty());
+}
+
+#[test]
+// Leaks on purpose, which Miri reports as an error
+#[cfg_attr(miri, ignore)]
+fn test_drain_leaked() {
+    let mut stack = create_stack();
+    std::mem::forget(stack.drain(3..5));
+    // The elements of the range and above are leaked, the ones below are kept
+    assert_eq!(format!("{}", stack), "Top -> [-5, 6, -7, 8]");
+}
+
+#[test]
+#[should_panic(expected = "index 10 out of bounds (length is 9)")]
+fn test_drain_out_of_bounds() {
+    create_stack().drain(5..10);
+}
+
+#[test]
+fn test_from_iter_and_extend() {
+    let stack: Stack<i32> = STACK_ARRAY.iter().rev().copied().collect();
+    assert_eq!(format!("{}", stack), STACK_DISPLAY);
+    let mut stack = Stack::with_capacity(2);
+    stack.extend(0..20);
+    assert_eq!(stack.length(), 20);
+    assert_eq!(stack.top(), Some(&19));
+    stack.extend(&[20, 21]);
+    assert_eq!(stack.pop(), Some(21));
+    assert_eq!(stack.pop(), Some(20));
+}
+
+#[test]
+fn test_vec_conversions() {
+    let vec: Vec<i32> = STACK_ARRAY.iter().rev().copied().collect();
+    let mut stack = Stack::from(vec);
+    assert_eq!(stack, create_stack());
+    // The last element of the vector is on top, and pushing grows the adopted buffer
+    stack.push(9);
+    assert_eq!(stack.top(), Some(&9));
+    assert_eq!(Stack::from([1, 2, 3]).to_string(), "Top -> [3, 2, 1]");
+    let vec = Vec::from(create_stack());
+    assert!(vec.iter().eq(STACK_ARRAY.iter().rev()));
+    // Converting back and forth keeps the same buffer
+    let vec_ptr = vec.as_ptr();
+    let vec = Vec::from(Stack::from(vec));
+    assert_eq!(vec.as_ptr(), vec_ptr);
+    assert_eq!(Vec::from(Stack::<i32>::new()), []);
+}
+
+#[test]
+fn test_debug_and_ord() {
+    let stack = create_stack();
+    assert_eq!(format!("{:?}", stack), "[0, -1, 2, -3, 4, -5, 6, -7, 8]");
+    // Compared from the top: a higher top wins whatever is below
+    let mut other = create_stack();
+    other.push(1);
+    assert!(other > stack);
+    other.pop();
+    assert_eq!(other.cmp(&stack), std::cmp::Ordering::Equal);
+    other.pop();
+    assert!(other < stack);
+    assert_eq!(Stack::from([f64::NAN]).partial_cmp(&Stack::from([0.0])), None);
+    assert_eq!(Stack::<i32>::default(), Stack::new());
+}
+
+#[test]
+fn test_send_to_thread() {
+    let stack = create_stack();
+    let handle = std::thread::spawn(move || stack.iter().sum::<i32>());
+    assert_eq!(handle.join().unwrap(), STACK_ARRAY.iter().sum::<i32>());
+}
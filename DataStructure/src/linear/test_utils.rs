@@ -0,0 +1,66 @@
+//! Helpers shared by the test modules of the collections, and the tests run alike on each of
+//! the growable buffer-backed collections.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use super::{deque, queue, stack, Deque, Queue, Stack};
+
+/// Hash of `value` with the default hasher
+pub(super) fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Check `Clone`, `Eq` and `Hash` of a collection: `collection` equals its clone and `same`,
+/// built another way from the same elements, with equal hashes, and a clone changed by `edit`
+/// differs from it
+pub(super) fn assert_clone_eq_hash<C: Clone + Eq + Hash>(
+    collection: &C,
+    same: &C,
+    edit: impl FnOnce(&mut C),
+) {
+    let mut clone = collection.clone();
+    assert!(clone == *collection);
+    assert_eq!(hash_of(&clone), hash_of(collection));
+    assert!(*same == *collection);
+    assert_eq!(hash_of(same), hash_of(collection));
+    edit(&mut clone);
+    assert!(clone != *collection);
+}
+
+const ITEMS: [i32; 9] = [0, -1, 2, -3, 4, -5, 6, -7, 8];
+
+/// Payload implementing none of the traits the collections implement themselves
+struct Handle(i32);
+
+#[test]
+fn test_non_clone_items() {
+    let mut stack = Stack::new();
+    let mut queue: Queue<Box<dyn FnOnce() -> i32>> = Queue::new();
+    let mut deque = Deque::new();
+    for item in ITEMS {
+        stack.push(Handle(item));
+        queue.push(Box::new(move || item));
+        deque.push_front(Handle(item));
+    }
+    assert_eq!(stack.top().unwrap().0, ITEMS[ITEMS.len() - 1]);
+    for item in ITEMS.iter().rev() {
+        assert_eq!(stack.pop().unwrap().0, *item);
+    }
+    for item in ITEMS {
+        assert_eq!(queue.pop().unwrap()(), item);
+        assert_eq!(deque.pop_back().unwrap().0, item);
+    }
+}
+
+#[test]
+fn test_drain_send_sync() {
+    // Compile-time check: drains are `Send` and `Sync` when their elements are, see the
+    // `compile_fail` examples of the ring buffer `Drain` for the other way around
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<stack::Drain<'_, i32>>();
+    assert_send_sync::<queue::Drain<'_, i32>>();
+    assert_send_sync::<deque::Drain<'_, i32>>();
+}
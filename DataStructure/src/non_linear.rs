@@ -1 +1,3 @@
 pub mod bst;
+
+pub use bst::BSTree;
@@ -1,3 +1,7 @@
+#[cfg(test)]
 mod tests;
 mod node;
 pub mod bs_tree;
+pub mod iter;
+
+pub use bs_tree::BSTree;
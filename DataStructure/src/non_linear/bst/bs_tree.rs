@@ -1,7 +1,12 @@
-use std::cmp::{PartialOrd, PartialEq};
-use std::fmt::{write, Display, Formatter};
+use std::cmp::{Ordering, PartialOrd, PartialEq};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use super::iter::{InOrderIter, LevelOrderIter, PostOrderIter, PreOrderIter};
 use super::node::BSNode;
 
+/// Clones keep the shape of the tree. Comparisons and hashing only look at the values, in
+/// order: trees holding the same values are equal whatever their shape. Trees are not [`Ord`],
+/// whose `min` and `max` would take precedence over [`min`](Self::min) and [`max`](Self::max).
 pub struct BSTree<T: PartialOrd + PartialEq> {
     root: Option<Box<BSNode<T>>>,
     size: usize,
@@ -18,14 +23,10 @@ impl<T: PartialOrd + PartialEq> BSTree<T> {
 
     pub fn insert(&mut self, value: T) {
         let new_boxed_node = Box::new(BSNode::new(value));
-        if self.root.is_none() {
-            // Tree is empty, the value will be the tree's root
-            self.root = Some(new_boxed_node);
-            self.size += 1;
-        } else {
+        if let Some(root) = &mut self.root {
             // Tree must be traversed and then inserted the node
-            let mut checking_boxed_node = self.root.as_mut().unwrap();
-            while true {
+            let mut checking_boxed_node = root;
+            loop {
                 if new_boxed_node.value == checking_boxed_node.value {
                     //Value already exists in the tree - No insert
                     return;
@@ -58,63 +59,41 @@ impl<T: PartialOrd + PartialEq> BSTree<T> {
                     }
                 }
             }
+        } else {
+            // Tree is empty, the value will be the tree's root
+            self.root = Some(new_boxed_node);
+            self.size += 1;
         }
     }
 
-    fn find_min_node<'a>(root: &'a Option<Box<BSNode<T>>>) -> Option<&'a Option<Box<BSNode<T>>>> {
-        match root {
-            None => None,
-            Some(root_node) => {
-                match root_node.left() {
-                    None => Some(root),
-                    Some(_) => Self::find_min_node(root_node.left()),
-                }
-            }
-        }
-    }
-    fn find_min_node_mut<'a>(root: &'a mut Option<Box<BSNode<T>>>) -> Option<&'a mut Option<Box<BSNode<T>>>> {
-        match root {
-            None => None,
-            Some(_) => {
-                let is_none = root.as_mut().unwrap().left().is_none();
-                if is_none {
-                    Some(root)
-                } else {
-                    Self::find_min_node_mut(root.as_mut().unwrap().left_mut())
-                }
-            }
+    fn find_min_node(root: &Option<Box<BSNode<T>>>) -> Option<&Option<Box<BSNode<T>>>> {
+        let mut checking_boxed_node = root;
+        // Walk down the left children until the node without any
+        while checking_boxed_node.as_ref()?.left().is_some() {
+            checking_boxed_node = checking_boxed_node.as_ref().unwrap().left();
         }
+        Some(checking_boxed_node)
     }
 
-    fn find_max_node<'a>(root: &'a Option<Box<BSNode<T>>>) -> Option<&'a Option<Box<BSNode<T>>>> {
-        match root {
-            None => None,
-            Some(root_node) => {
-                match root_node.right() {
-                    None => Some(root),
-                    Some(_) => Self::find_min_node(root_node.right()),
-                }
-            }
+    fn find_max_node(root: &Option<Box<BSNode<T>>>) -> Option<&Option<Box<BSNode<T>>>> {
+        let mut checking_boxed_node = root;
+        while checking_boxed_node.as_ref()?.right().is_some() {
+            checking_boxed_node = checking_boxed_node.as_ref().unwrap().right();
         }
+        Some(checking_boxed_node)
     }
-    fn find_max_node_mut<'a>(root: &'a mut Option<Box<BSNode<T>>>) -> Option<&'a mut Option<Box<BSNode<T>>>> {
-        match root {
-            None => None,
-            Some(_) => {
-                let is_none = root.as_mut().unwrap().right().is_none();
-                if is_none {
-                    Some(root)
-                } else {
-                    Self::find_min_node_mut(root.as_mut().unwrap().right_mut())
-                }
-            }
+    fn find_max_node_mut(root: &mut Option<Box<BSNode<T>>>) -> Option<&mut Option<Box<BSNode<T>>>> {
+        let mut checking_boxed_node = root;
+        while checking_boxed_node.as_ref()?.right().is_some() {
+            checking_boxed_node = checking_boxed_node.as_mut().unwrap().right_mut();
         }
+        Some(checking_boxed_node)
     }
 
     fn find_node(&self, value: &T) -> &Option<Box<BSNode<T>>> {
         let mut checking_boxed_node = &self.root;
         // USe of _ to not assign a mut ref that will put the new checking_boxed_node ref invalid as another one has been used for a variable that could edit the content of referred item
-        while let Some(_) = checking_boxed_node {
+        while checking_boxed_node.is_some() {
             let node = checking_boxed_node.as_ref().unwrap();
             if node.value == *value {
                 break;
@@ -131,7 +110,7 @@ impl<T: PartialOrd + PartialEq> BSTree<T> {
     fn find_node_mut(&mut self, value: &T) -> &mut Option<Box<BSNode<T>>> {
         let mut checking_boxed_node = &mut self.root;
         // Use of _ to not assign a mut ref that will put the new checking_boxed_node ref invalid as another one has been used for a variable that could edit the content of referred item
-        while let Some(_) = checking_boxed_node {
+        while checking_boxed_node.is_some() {
             let node_value = &checking_boxed_node.as_ref().unwrap().value;
             if *node_value == *value {
                 break;
@@ -146,78 +125,229 @@ impl<T: PartialOrd + PartialEq> BSTree<T> {
     }
 
     pub fn find(&self, value: &T) -> Option<&T> {
-        match self.find_node(&value) {
+        match self.find_node(value) {
             None => None,
             Some(node) => Some(&node.value)
         }
     }
 
+    pub fn min(&self) -> Option<&T> {
+        Self::find_min_node(&self.root).map(|node_opt| &node_opt.as_ref().unwrap().value)
+    }
+
+    pub fn max(&self) -> Option<&T> {
+        Self::find_max_node(&self.root).map(|node_opt| &node_opt.as_ref().unwrap().value)
+    }
+
     pub fn remove(&mut self, value: &T) -> Option<T> {
-        let mut node_opt = self.find_node_mut(value);
-        let mut return_val = None;
-        if node_opt.is_none() {
-            return return_val;
-        }
+        let node_opt = self.find_node_mut(value);
 
         // Take out node to remove - Will be dropped at the end of the function
-        let mut removed_node = node_opt.take().unwrap();
-        let left_is_some = removed_node.left().is_some();
-        let right_is_some = removed_node.right().is_some();
+        let mut removed_node = node_opt.take()?;
 
-        let replace_node = if left_is_some {
+        *node_opt = if removed_node.left().is_some() {
+            // The max node of the left subtree replaces the removed one. Having no right child,
+            // its left subtree takes its place, then it inherits both subtrees of the removed node
             let max_node_opt = Self::find_max_node_mut(removed_node.left_mut()).unwrap();
-            Some(max_node_opt.take().unwrap())
-        } else if right_is_some {
-            let min_node_opt = Self::find_min_node_mut(removed_node.right_mut()).unwrap();
-            Some(min_node_opt.take().unwrap())
+            let mut replace_node = max_node_opt.take().unwrap();
+            *max_node_opt = replace_node.left_mut().take();
+            *replace_node.left_mut() = removed_node.left_mut().take();
+            *replace_node.right_mut() = removed_node.right_mut().take();
+            Some(replace_node)
         } else {
-            None
+            // No left subtree: the right one (if any) takes the place of the removed node
+            removed_node.right_mut().take()
         };
 
-        *node_opt = replace_node;
-        return_val = Some(removed_node.value);
-
         self.size -= 1;
-        return_val
+        Some(removed_node.value)
     }
 
-    pub fn in_order_values_builder<'a>(root: Option<&'a Box<BSNode<T>>>, vec: &mut Vec<&'a T>) {
-        if let Some(node) = root {
-            Self::in_order_values_builder(node.left().as_ref(), vec);
-            vec.push(&node.value);
-            Self::in_order_values_builder(node.right().as_ref(), vec);
-        }
+    pub fn in_order_iter(&self) -> InOrderIter<'_, T> {
+        InOrderIter::new(self.root.as_deref(), self.size)
     }
     pub fn in_order_values(&self) -> Vec<&T> {
-        let mut vec = Vec::new();
-        Self::in_order_values_builder(self.root.as_ref(), &mut vec);
-        vec
+        self.in_order_iter().collect()
     }
 
-    pub fn pre_order_values_builder<'a>(root: Option<&'a Box<BSNode<T>>>, vec: &mut Vec<&'a T>) {
-        if let Some(node) = root {
-            vec.push(&node.value);
-            Self::in_order_values_builder(node.left().as_ref(), vec);
-            Self::in_order_values_builder(node.right().as_ref(), vec);
-        }
+    pub fn pre_order_iter(&self) -> PreOrderIter<'_, T> {
+        PreOrderIter::new(self.root.as_deref(), self.size)
     }
     pub fn pre_order_values(&self) -> Vec<&T> {
-        let mut vec = Vec::new();
-        Self::pre_order_values_builder(self.root.as_ref(), &mut vec);
-        vec
+        self.pre_order_iter().collect()
     }
 
-    pub fn post_order_values_builder<'a>(root: Option<&'a Box<BSNode<T>>>, vec: &mut Vec<&'a T>) {
-        if let Some(node) = root {
-            Self::in_order_values_builder(node.left().as_ref(), vec);
-            Self::in_order_values_builder(node.right().as_ref(), vec);
-            vec.push(&node.value);
-        }
+    pub fn post_order_iter(&self) -> PostOrderIter<'_, T> {
+        PostOrderIter::new(self.root.as_deref(), self.size)
     }
     pub fn post_order_values(&self) -> Vec<&T> {
-        let mut vec = Vec::new();
-        Self::post_order_values_builder(self.root.as_ref(), &mut vec);
-        vec
+        self.post_order_iter().collect()
+    }
+
+    pub fn level_order_iter(&self) -> LevelOrderIter<'_, T> {
+        LevelOrderIter::new(self.root.as_deref(), self.size)
+    }
+    pub fn level_order_values(&self) -> Vec<&T> {
+        self.level_order_iter().collect()
+    }
+}
+
+impl<T: PartialOrd + PartialEq> BSTree<T> {
+    /// Build a balanced subtree out of the next `size` values of `values`, that come sorted
+    fn build_balanced(size: usize, values: &mut impl Iterator<Item = T>) -> Option<Box<BSNode<T>>> {
+        if size == 0 {
+            return None;
+        }
+        // Values are consumed in order: left subtree, then the node, then the right subtree
+        let left = Self::build_balanced(size / 2, values);
+        let mut node = Box::new(BSNode::new(values.next()?));
+        *node.left_mut() = left;
+        *node.right_mut() = Self::build_balanced(size - size / 2 - 1, values);
+        Some(node)
+    }
+}
+
+impl<T: PartialOrd + PartialEq> FromIterator<T> for BSTree<T> {
+    /// Sorted input builds a balanced tree in linear time, see [`From<Vec<T>>`](#impl-From<Vec<T>>-for-BSTree<T>)
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: PartialOrd + PartialEq> Extend<T> for BSTree<T> {
+    /// Insert the values in order. An empty tree is built like with [`FromIterator`].
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.size == 0 {
+            *self = iter.into_iter().collect();
+            return;
+        }
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a, T: PartialOrd + PartialEq + Copy + 'a> Extend<&'a T> for BSTree<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<T: PartialOrd + PartialEq> From<Vec<T>> for BSTree<T> {
+    /// Values sorted in ascending order build a balanced tree in linear time, duplicates being
+    /// ignored like with [`insert`](BSTree::insert). Other values are inserted one at a time.
+    fn from(mut vec: Vec<T>) -> Self {
+        if !vec.windows(2).all(|pair| pair[0] <= pair[1]) {
+            let mut tree = BSTree::new();
+            for value in vec {
+                tree.insert(value);
+            }
+            return tree;
+        }
+        vec.dedup();
+        let size = vec.len();
+        let root = Self::build_balanced(size, &mut vec.into_iter());
+        BSTree { root, size }
+    }
+}
+
+impl<T: PartialOrd + PartialEq, const N: usize> From<[T; N]> for BSTree<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from(Vec::from(array))
+    }
+}
+
+impl<T: PartialOrd + PartialEq> From<BSTree<T>> for Vec<T> {
+    /// Values come sorted, as with [`BSTree::in_order_iter`]
+    fn from(mut tree: BSTree<T>) -> Self {
+        let mut values = Vec::with_capacity(tree.size);
+        // In-order traversal taking the nodes apart: a node is stacked once its left subtree
+        // has been detached, and its right subtree is visited once the node is unstacked
+        let mut stack = Vec::new();
+        let mut node_opt = tree.root.take();
+        loop {
+            while let Some(mut node) = node_opt {
+                node_opt = node.left_mut().take();
+                stack.push(node);
+            }
+            let Some(mut node) = stack.pop() else {
+                break;
+            };
+            node_opt = node.right_mut().take();
+            values.push(node.value);
+        }
+        values
+    }
+}
+
+impl<T: Clone + PartialOrd + PartialEq> Clone for BSTree<T> {
+    fn clone(&self) -> Self {
+        let mut root = None;
+        // Pre-order traversal pairing each node with the empty slot its copy goes in, so that
+        // degenerate trees don't overflow the stack
+        let mut stack = Vec::new();
+        if let Some(node) = self.root.as_deref() {
+            stack.push((node, &mut root));
+        }
+        while let Some((node, slot)) = stack.pop() {
+            let copy = slot.insert(Box::new(BSNode::new(node.value.clone())));
+            let (left, right) = copy.children_mut();
+            if let Some(right_node) = node.right() {
+                stack.push((right_node, right));
+            }
+            if let Some(left_node) = node.left() {
+                stack.push((left_node, left));
+            }
+        }
+        BSTree { root, size: self.size }
+    }
+}
+
+impl<T: PartialOrd + PartialEq> Drop for BSTree<T> {
+    fn drop(&mut self) {
+        // Detach the nodes one at a time, dropping boxes recursively would overflow the stack on
+        // degenerate trees
+        let mut stack: Vec<_> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left_mut().take());
+            stack.extend(node.right_mut().take());
+        }
+    }
+}
+
+impl<T: Debug + PartialOrd + PartialEq> Debug for BSTree<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.in_order_iter()).finish()
+    }
+}
+
+impl<T: PartialOrd + PartialEq> PartialEq for BSTree<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.in_order_iter().eq(other.in_order_iter())
+    }
+}
+
+impl<T: PartialOrd + Eq> Eq for BSTree<T> {}
+
+impl<T: PartialOrd + PartialEq> PartialOrd for BSTree<T> {
+    /// Lexicographic comparison of the values in order
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.in_order_iter().partial_cmp(other.in_order_iter())
+    }
+}
+
+impl<T: PartialOrd + PartialEq + Hash> Hash for BSTree<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        for value in self.in_order_iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: PartialOrd + PartialEq> Default for BSTree<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -0,0 +1,142 @@
+//! Lazy traversals of a [`BSTree`](super::BSTree).
+//!
+//! Every iterator keeps only the nodes still to be visited: a stack as high as the tree for the
+//! depth-first orders, a queue as wide as the tree for the level order.
+
+use std::collections::VecDeque;
+
+use super::node::BSNode;
+
+/// Depth-first traversal visiting the left subtree, then the node, then the right subtree:
+/// values come out sorted.
+pub struct InOrderIter<'a, T> {
+    stack: Vec<&'a BSNode<T>>,
+    remaining: usize,
+}
+
+/// Depth-first traversal visiting the node, then its left and right subtrees.
+pub struct PreOrderIter<'a, T> {
+    stack: Vec<&'a BSNode<T>>,
+    remaining: usize,
+}
+
+/// Depth-first traversal visiting the left and right subtrees, then the node.
+pub struct PostOrderIter<'a, T> {
+    // Each node is paired with a flag telling whether its children have already been stacked
+    stack: Vec<(&'a BSNode<T>, bool)>,
+    remaining: usize,
+}
+
+/// Breadth-first traversal visiting the nodes level by level, from left to right.
+pub struct LevelOrderIter<'a, T> {
+    queue: VecDeque<&'a BSNode<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> InOrderIter<'a, T> {
+    pub(super) fn new(root: Option<&'a BSNode<T>>, size: usize) -> Self {
+        let mut iter = InOrderIter { stack: Vec::new(), remaining: size };
+        iter.push_left_branch(root);
+        iter
+    }
+
+    fn push_left_branch(&mut self, mut node_opt: Option<&'a BSNode<T>>) {
+        while let Some(node) = node_opt {
+            self.stack.push(node);
+            node_opt = node.left().as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_branch(node.right().as_deref());
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> PreOrderIter<'a, T> {
+    pub(super) fn new(root: Option<&'a BSNode<T>>, size: usize) -> Self {
+        PreOrderIter { stack: root.into_iter().collect(), remaining: size }
+    }
+}
+
+impl<'a, T> Iterator for PreOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        // Right is pushed first so that the left subtree is visited first
+        self.stack.extend(node.right().as_deref());
+        self.stack.extend(node.left().as_deref());
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> PostOrderIter<'a, T> {
+    pub(super) fn new(root: Option<&'a BSNode<T>>, size: usize) -> Self {
+        PostOrderIter { stack: root.map(|node| (node, false)).into_iter().collect(), remaining: size }
+    }
+}
+
+impl<'a, T> Iterator for PostOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, children_stacked)) = self.stack.pop() {
+            if children_stacked {
+                self.remaining -= 1;
+                return Some(&node.value);
+            }
+            // Visit the node again once both subtrees have been visited, left one first
+            self.stack.push((node, true));
+            self.stack.extend(node.right().as_deref().map(|right| (right, false)));
+            self.stack.extend(node.left().as_deref().map(|left| (left, false)));
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> LevelOrderIter<'a, T> {
+    pub(super) fn new(root: Option<&'a BSNode<T>>, size: usize) -> Self {
+        LevelOrderIter { queue: root.into_iter().collect(), remaining: size }
+    }
+}
+
+impl<'a, T> Iterator for LevelOrderIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(node.left().as_deref());
+        self.queue.extend(node.right().as_deref());
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for InOrderIter<'_, T> {}
+impl<T> ExactSizeIterator for PreOrderIter<'_, T> {}
+impl<T> ExactSizeIterator for PostOrderIter<'_, T> {}
+impl<T> ExactSizeIterator for LevelOrderIter<'_, T> {}
@@ -1,3 +1,6 @@
+/// Child link of a node
+pub type Subtree<T> = Option<Box<BSNode<T>>>;
+
 pub struct BSNode<T> {
     pub value: T,
     left: Option<Box<BSNode<T>>>,
@@ -26,4 +29,8 @@ impl<T> BSNode<T> {
     pub fn right_mut(&mut self) -> &mut Option<Box<BSNode<T>>> {
         &mut self.right
     }
+
+    pub fn children_mut(&mut self) -> (&mut Subtree<T>, &mut Subtree<T>) {
+        (&mut self.left, &mut self.right)
+    }
 }
@@ -1,8 +1,11 @@
 use crate::non_linear::bst::bs_tree::BSTree;
+use std::collections::{BTreeSet, HashSet};
+use std::iter;
+use std::thread;
 
 const TREE_VALUES: [i32; 9] = [5, -1, -2, -3, 7, -9, 0, 10, 4];
-const TREE_DISPLAY: &'static str = "[-9, -3, -2, -1, 0, 4, 5, 7, 10]";
-const TREE_DISPLAY_REMOVED: &'static str = "[-9, -3, -2, -1, 4, 5, 7, 10]";
+const TREE_DISPLAY: &str = "[-9, -3, -2, -1, 0, 4, 5, 7, 10]";
+const TREE_DISPLAY_REMOVED: &str = "[-9, -3, -2, -1, 4, 5, 7, 10]";
 
 fn create_empty_tree() -> BSTree<i32> {
     BSTree::<i32>::new()
@@ -17,6 +20,13 @@ fn create_tree() -> BSTree<i32> {
     tree
 }
 
+#[test]
+fn test_new() {
+    let tree = create_empty_tree();
+    assert_eq!(tree.size(), 0);
+    assert!(tree.find(&0).is_none());
+}
+
 #[test]
 fn test_find() {
     let tree = create_tree();
@@ -31,9 +41,9 @@ fn test_find() {
 fn test_insert() {
     let mut tree = create_tree();
     let start_size = tree.size();
-    let result = tree.insert(99);
+    tree.insert(99);
     assert_eq!(tree.size(), start_size + 1);
-    let result = tree.find(&99);
+    let _result = tree.find(&99);
     assert_eq!(tree.size(), start_size + 1);
 }
 
@@ -56,4 +66,227 @@ fn test_display() {
     assert_eq!(format!("{}", tree), TREE_DISPLAY);
     let _ = tree.remove(&0);
     assert_eq!(format!("{}", tree), TREE_DISPLAY_REMOVED);
-}
\ No newline at end of file
+}
+
+// Shape of the tree built from TREE_VALUES:
+//              5
+//          /       \
+//        -1         7
+//       /   \        \
+//     -2     0        10
+//     /       \
+//   -3         4
+//   /
+// -9
+const TREE_PRE_ORDER: [i32; 9] = [5, -1, -2, -3, -9, 0, 4, 7, 10];
+const TREE_POST_ORDER: [i32; 9] = [-9, -3, -2, 4, 0, -1, 10, 7, 5];
+const TREE_LEVEL_ORDER: [i32; 9] = [5, -1, 7, -2, 0, 10, -3, 4, -9];
+
+#[test]
+fn test_traversals() {
+    let tree = create_tree();
+    let mut sorted = TREE_VALUES;
+    sorted.sort();
+    assert!(tree.in_order_values().into_iter().eq(sorted.iter()));
+    assert!(tree.pre_order_values().into_iter().eq(TREE_PRE_ORDER.iter()));
+    assert!(tree.post_order_values().into_iter().eq(TREE_POST_ORDER.iter()));
+    assert!(tree.level_order_values().into_iter().eq(TREE_LEVEL_ORDER.iter()));
+}
+
+#[test]
+fn test_traversals_empty() {
+    let tree = create_empty_tree();
+    assert!(tree.in_order_values().is_empty());
+    assert_eq!(tree.pre_order_iter().next(), None);
+    assert_eq!(tree.post_order_iter().next(), None);
+    assert_eq!(tree.level_order_iter().next(), None);
+}
+
+#[test]
+fn test_traversal_iterators_are_lazy() {
+    let tree = create_tree();
+    let mut iter = tree.pre_order_iter();
+    assert_eq!(iter.len(), TREE_VALUES.len());
+    assert_eq!(iter.next(), Some(&5));
+    assert_eq!(iter.next(), Some(&-1));
+    assert_eq!(iter.len(), TREE_VALUES.len() - 2);
+    let level_order: Vec<&i32> = tree.level_order_iter().take(3).collect();
+    assert_eq!(level_order, [&5, &-1, &7]);
+    assert_eq!(tree.post_order_iter().skip(5).len(), TREE_VALUES.len() - 5);
+}
+
+#[test]
+fn test_remove_keeps_subtrees() {
+    let mut tree = create_tree();
+    // 5 is the root: both of its subtrees must survive its removal
+    assert_eq!(tree.remove(&5), Some(5));
+    assert_eq!(format!("{}", tree), "[-9, -3, -2, -1, 0, 4, 7, 10]");
+    // -1 has two children, and the max of its left subtree (-2) has a left child
+    assert_eq!(tree.remove(&-1), Some(-1));
+    assert_eq!(format!("{}", tree), "[-9, -3, -2, 0, 4, 7, 10]");
+    assert_eq!(tree.size(), TREE_VALUES.len() - 2);
+    for value in [-9, -3, -2, 0, 4, 7, 10] {
+        assert_eq!(tree.find(&value), Some(&value));
+    }
+}
+
+#[test]
+fn test_min_max() {
+    let mut tree = create_empty_tree();
+    assert!(tree.min().is_none());
+    assert!(tree.max().is_none());
+    for val in TREE_VALUES {
+        tree.insert(val);
+    }
+    assert_eq!(tree.min(), Some(&-9));
+    assert_eq!(tree.max(), Some(&10));
+}
+
+/// Xorshift generator, to get reproducible pseudo-random operations without dependencies
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn assert_invariants(tree: &BSTree<i32>, expected: &BTreeSet<i32>) {
+    let values = tree.in_order_values();
+    assert_eq!(tree.size(), expected.len());
+    assert_eq!(values.len(), expected.len());
+    assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(values.iter().copied().eq(expected.iter()));
+    assert_eq!(tree.min(), expected.first());
+    assert_eq!(tree.max(), expected.last());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_random_operations() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..50 {
+        let mut tree = create_empty_tree();
+        let mut expected = BTreeSet::new();
+        for _ in 0..300 {
+            let value = (rng.next() % 64) as i32;
+            // Remove once every three operations on average
+            match rng.next() % 3 {
+                0 => assert_eq!(tree.remove(&value), expected.take(&value)),
+                _ => {
+                    tree.insert(value);
+                    expected.insert(value);
+                }
+            }
+            assert_invariants(&tree, &expected);
+        }
+        for value in expected.clone() {
+            assert_eq!(tree.remove(&value), Some(value));
+            expected.remove(&value);
+            assert_invariants(&tree, &expected);
+        }
+    }
+}
+
+#[test]
+fn test_from_sorted_is_balanced() {
+    let tree: BSTree<i32> = (1..=7).collect();
+    assert_eq!(tree.size(), 7);
+    assert!(tree.pre_order_values().into_iter().eq([4, 2, 1, 3, 6, 5, 7].iter()));
+    // Duplicates are dropped like with insert
+    let tree = BSTree::from(vec![1, 1, 2, 3, 3, 3]);
+    assert_eq!(tree.size(), 3);
+    assert!(tree.level_order_values().into_iter().eq([2, 1, 3].iter()));
+    let tree: BSTree<i32> = (0..1000).collect();
+    assert_eq!(tree.level_order_values()[..3], [&500, &250, &750]);
+    assert_eq!(Vec::from(tree), (0..1000).collect::<Vec<i32>>());
+}
+
+#[test]
+fn test_conversions() {
+    // Unsorted values are inserted in order, giving the same shape as create_tree
+    let tree = BSTree::from(TREE_VALUES);
+    assert!(tree.pre_order_values().into_iter().eq(TREE_PRE_ORDER.iter()));
+    let expected: BTreeSet<i32> = TREE_VALUES.into_iter().collect();
+    assert_invariants(&tree, &expected);
+    assert!(Vec::from(tree).into_iter().eq(expected.iter().copied()));
+    let mut tree = create_tree();
+    tree.extend([3, 5, 20]);
+    tree.extend(&[-20]);
+    let mut expected = expected;
+    expected.extend([3, 5, 20, -20]);
+    assert_invariants(&tree, &expected);
+    // Extending an empty tree builds it like collect
+    let mut tree = create_empty_tree();
+    tree.extend(1..=7);
+    assert!(tree.pre_order_values().into_iter().eq([4, 2, 1, 3, 6, 5, 7].iter()));
+    assert_eq!(Vec::from(create_empty_tree()), []);
+}
+
+#[test]
+fn test_standard_traits() {
+    let tree = create_tree();
+    assert_eq!(format!("{:?}", tree), "{-9, -3, -2, -1, 0, 4, 5, 7, 10}");
+    // Clones keep the shape
+    let mut clone = tree.clone();
+    assert!(clone.pre_order_values().into_iter().eq(TREE_PRE_ORDER.iter()));
+    assert_eq!(clone, tree);
+    // Same values with another shape are equal
+    let mut sorted = TREE_VALUES;
+    sorted.sort();
+    let balanced = BSTree::from(sorted);
+    assert!(!balanced.pre_order_values().into_iter().eq(TREE_PRE_ORDER.iter()));
+    assert_eq!(balanced, tree);
+    let set: HashSet<BSTree<i32>> = [tree, balanced].into();
+    assert_eq!(set.len(), 1);
+    clone.remove(&-9);
+    assert!(clone > create_tree());
+    clone.insert(-10);
+    assert!(clone < create_tree());
+    assert_eq!(BSTree::<i32>::default(), create_empty_tree());
+}
+
+// Deep enough for recursive walks of a degenerate tree to overflow DEGENERATE_STACK_SIZE
+const DEGENERATE_SIZE: i32 = 10_000;
+const DEGENERATE_STACK_SIZE: usize = 128 * 1024;
+
+#[test]
+// Too slow under Miri
+#[cfg_attr(miri, ignore)]
+fn test_degenerate_tree() {
+    // Run on a small stack, independent of the size given to the test threads
+    let thread = thread::Builder::new().stack_size(DEGENERATE_STACK_SIZE);
+    thread.spawn(check_degenerate_tree).unwrap().join().unwrap();
+}
+
+fn check_degenerate_tree() {
+    // Sorted inserts chain every node to the right of the previous one
+    let mut tree = create_empty_tree();
+    for val in 0..DEGENERATE_SIZE {
+        tree.insert(val);
+    }
+    let clone = tree.clone();
+    assert_eq!(clone.size(), tree.size());
+    assert!(clone.pre_order_iter().copied().eq(0..DEGENERATE_SIZE));
+    assert_eq!(tree.min(), Some(&0));
+    assert_eq!(tree.max(), Some(&(DEGENERATE_SIZE - 1)));
+    assert_eq!(tree.remove(&(DEGENERATE_SIZE - 1)), Some(DEGENERATE_SIZE - 1));
+    assert_eq!(tree.max(), Some(&(DEGENERATE_SIZE - 2)));
+    // Reverse sorted inserts chain them to the left
+    let reversed: BSTree<i32> = (0..DEGENERATE_SIZE).rev().collect();
+    assert_eq!(reversed.min(), Some(&0));
+    assert_eq!(reversed.max(), Some(&(DEGENERATE_SIZE - 1)));
+    // Removing a root greater than the chain on its left moves the bottom of the chain up
+    let mut tree = create_empty_tree();
+    tree.insert(DEGENERATE_SIZE);
+    for val in 0..DEGENERATE_SIZE {
+        tree.insert(val);
+    }
+    assert_eq!(tree.remove(&DEGENERATE_SIZE), Some(DEGENERATE_SIZE));
+    assert_eq!(tree.size(), DEGENERATE_SIZE as usize);
+    let pre_order = iter::once(DEGENERATE_SIZE - 1).chain(0..DEGENERATE_SIZE - 1);
+    assert!(tree.pre_order_iter().copied().eq(pre_order));
+}
@@ -0,0 +1,15 @@
+//! Re-exports of every collection of the crate, to be glob-imported.
+//!
+//! ```
+//! use data_structure::prelude::*;
+//!
+//! let mut queue = Queue::new();
+//! queue.push("first");
+//! assert_eq!(queue.pop(), Some("first"));
+//! ```
+
+pub use crate::linear::{
+    ArrayDeque, ArrayStack, BoundedDeque, BoundedQueue, BoundedStack, Deque, DoubleLinkedList,
+    GrowthPolicy, Queue, SinglyLinkedList, SmallStack, Stack,
+};
+pub use crate::non_linear::BSTree;